@@ -1,4 +1,5 @@
 use dioxus_core::Template;
+use freya_native_core::NodeId;
 use torin::prelude::CursorPoint;
 use uuid::Uuid;
 use winit::window::{
@@ -6,6 +7,8 @@ use winit::window::{
     Window,
 };
 
+use crate::SnapshotConfig;
+
 pub struct TextGroupMeasurement {
     pub text_id: Uuid,
     pub cursor_id: usize,
@@ -13,6 +16,14 @@ pub struct TextGroupMeasurement {
     pub cursor_selection: Option<(CursorPoint, CursorPoint)>,
 }
 
+/// Request to render a Node and its descendants into an encoded image.
+pub struct NodeSnapshotRequest {
+    pub node_id: NodeId,
+    pub config: SnapshotConfig,
+    /// Called with the encoded image, or `None` if the Node couldn't be rendered.
+    pub callback: Box<dyn FnOnce(Option<Vec<u8>>) + Send + Sync>,
+}
+
 /// Custom EventLoop messages
 pub enum EventMessage {
     /// Update the given template
@@ -39,6 +50,8 @@ pub enum EventMessage {
     ExitApp,
     /// Callback to access the Window.
     WithWindow(Box<dyn FnOnce(&Window) + Send + Sync>),
+    /// Render a Node into an image
+    RenderNodeSnapshot(NodeSnapshotRequest),
 }
 
 impl From<accesskit_winit::Event> for EventMessage {
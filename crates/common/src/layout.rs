@@ -1,6 +1,7 @@
 use std::ops::Div;
 
 use freya_engine::prelude::Paragraph;
+use freya_native_core::NodeId;
 use torin::geometry::{
     Area,
     Size2D,
@@ -11,6 +12,8 @@ use torin::geometry::{
 pub struct NodeReferenceLayout {
     pub area: Area,
     pub inner: Size2D,
+    /// The referenced Node, `None` until it has been measured.
+    pub node_id: Option<NodeId>,
 }
 
 impl NodeReferenceLayout {
@@ -36,3 +39,11 @@ pub struct CachedParagraph(pub Paragraph);
 /// In the main thread when measuring the layout and painting.
 unsafe impl Send for CachedParagraph {}
 unsafe impl Sync for CachedParagraph {}
+
+/// Outlines of the text spans of a `paragraph` that have a `text_stroke`.
+pub struct CachedStrokeParagraph(pub Paragraph);
+
+/// # Safety
+/// Same as [CachedParagraph].
+unsafe impl Send for CachedStrokeParagraph {}
+unsafe impl Sync for CachedStrokeParagraph {}
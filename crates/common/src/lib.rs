@@ -2,8 +2,12 @@ mod event_messages;
 mod layers;
 mod layout;
 mod paragraphs;
+mod references;
+mod snapshot;
 
 pub use event_messages::*;
 pub use layers::*;
 pub use layout::*;
 pub use paragraphs::*;
+pub use references::*;
+pub use snapshot::*;
@@ -0,0 +1,29 @@
+use std::sync::{
+    Arc,
+    Mutex,
+    MutexGuard,
+};
+
+use freya_native_core::NodeId;
+use rustc_hash::FxHashSet;
+
+/// Elements with an image or canvas reference,
+/// their content might change without any of their states changing.
+#[derive(Default, Clone)]
+pub struct ReferencedElements {
+    pub elements: Arc<Mutex<FxHashSet<NodeId>>>,
+}
+
+impl ReferencedElements {
+    pub fn insert_element(&self, node_id: NodeId) {
+        self.elements.lock().unwrap().insert(node_id);
+    }
+
+    pub fn remove_element(&self, node_id: NodeId) {
+        self.elements.lock().unwrap().remove(&node_id);
+    }
+
+    pub fn elements(&self) -> MutexGuard<FxHashSet<NodeId>> {
+        self.elements.lock().unwrap()
+    }
+}
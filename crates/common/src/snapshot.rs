@@ -0,0 +1,22 @@
+use freya_engine::prelude::EncodedImageFormat;
+
+/// How a Node snapshot is rendered and encoded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotConfig {
+    /// Scale of the snapshot relative to the size of the Node in the window.
+    pub scale: f32,
+    /// Format the snapshot is encoded with.
+    pub format: EncodedImageFormat,
+    /// Quality from `0` to `100`, only used by lossy formats such as JPEG or WebP.
+    pub quality: u32,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            format: EncodedImageFormat::PNG,
+            quality: 100,
+        }
+    }
+}
@@ -111,7 +111,9 @@ pub fn Button(
     let onpointerup = {
         to_owned![onpress, onclick];
         move |ev: PointerEvent| {
-            focus.focus();
+            if !ev.default_prevented() {
+                focus.focus();
+            }
             if let Some(onpress) = &onpress {
                 let is_valid = match ev.data.pointer_type {
                     PointerType::Mouse {
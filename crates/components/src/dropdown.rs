@@ -212,8 +212,10 @@ where
         opened.set(false);
     };
 
-    let onclick = move |_| {
-        focus.focus();
+    let onclick = move |e: MouseEvent| {
+        if !e.default_prevented() {
+            focus.focus();
+        }
         opened.set(true)
     };
 
@@ -3,6 +3,7 @@ use freya_elements::{
     elements as dioxus_elements,
     events::{
         keyboard::Key,
+        CompositionEvent,
         KeyboardData,
         MouseEvent,
     },
@@ -119,19 +120,32 @@ pub fn Input(
     });
 
     let onkeydown = move |e: Event<KeyboardData>| {
-        if focus.is_focused() && e.data.key != Key::Enter {
+        if focus.is_focused() && e.data.key != Key::Enter && !e.default_prevented() {
             editable.process_event(&EditableEvent::KeyDown(e.data));
             onchange.call(editable.editor().peek().to_string());
         }
     };
 
     let onkeyup = move |e: Event<KeyboardData>| {
-        if focus.is_focused() {
+        if focus.is_focused() && !e.default_prevented() {
             editable.process_event(&EditableEvent::KeyUp(e.data));
         }
     };
 
+    let oncompositionupdate = move |e: CompositionEvent| {
+        if focus.is_focused() {
+            editable.process_event(&EditableEvent::Composition(e.data));
+        }
+    };
+
+    let oncompositionend = move |_| {
+        editable.process_event(&EditableEvent::CompositionEnd);
+    };
+
     let onmousedown = move |e: MouseEvent| {
+        if e.default_prevented() {
+            return;
+        }
         if !display_placeholder {
             editable.process_event(&EditableEvent::MouseDown(e.data, 0));
         }
@@ -166,10 +180,24 @@ pub fn Input(
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
 
+    // Hidden inputs don't show what is being composed
+    let preedit = editable
+        .preedit()
+        .filter(|_| focus.is_focused() && mode == InputMode::Shown);
+    let cursor_pos = editable.editor().read().visible_cursor_pos();
+
     let (background, cursor_char) = if focus.is_focused() {
+        // Place the cursor where the IME says within the text being composed
+        let preedit_cursor = preedit.as_ref().map_or(0, |preedit| {
+            let end = preedit.cursor.map_or(preedit.text.len(), |(_, end)| end);
+            preedit
+                .text
+                .get(..end)
+                .map_or(0, |text| text.chars().count())
+        });
         (
             theme.hover_background,
-            editable.editor().read().visible_cursor_pos().to_string(),
+            (cursor_pos + preedit_cursor).to_string(),
         )
     } else {
         (theme.background, "none".to_string())
@@ -196,6 +224,21 @@ pub fn Input(
         (InputMode::Shown, _) => value,
     };
 
+    // Split the text at the cursor to show the text being composed in between
+    let (text, preedit, text_after) = match preedit {
+        Some(preedit) => {
+            let split = text
+                .char_indices()
+                .nth(cursor_pos)
+                .map_or(text.len(), |(i, _)| i);
+            let text_after = text[split..].to_string();
+            let mut text = text;
+            text.truncate(split);
+            (text, preedit.text, text_after)
+        }
+        None => (text, String::new(), String::new()),
+    };
+
     rsx!(
         rect {
             width: "{width}",
@@ -220,6 +263,8 @@ pub fn Input(
                 onmouseleave,
                 onmousedown,
                 onmouseover,
+                oncompositionupdate,
+                oncompositionend,
                 width: "100%",
                 cursor_id: "0",
                 cursor_index: "{cursor_char}",
@@ -230,6 +275,15 @@ pub fn Input(
                 text {
                     "{text}"
                 }
+                if !preedit.is_empty() {
+                    text {
+                        decoration: "underline",
+                        "{preedit}"
+                    }
+                    text {
+                        "{text_after}"
+                    }
+                }
             }
         }
     )
@@ -286,5 +340,36 @@ mod test {
 
         // Check that "d" has been written into the input.
         assert_eq!(text.get(0).text(), Some("Hello, World"));
+
+        // Compose "你好" with the IME
+        for name in [EventName::CompositionStart, EventName::CompositionUpdate] {
+            utils.push_event(PlatformEvent::Composition {
+                name,
+                text: "你好".to_string(),
+                cursor: Some((6, 6)),
+            });
+        }
+        utils.wait_for_update().await;
+
+        // Check that the composition is shown after the text but not written yet
+        let paragraph = root.get(0).get(0);
+        assert_eq!(text.get(0).text(), Some("Hello, World"));
+        assert_eq!(paragraph.get(1).get(0).text(), Some("你好"));
+
+        // Commit the composition
+        utils.push_event(PlatformEvent::Composition {
+            name: EventName::CompositionEnd,
+            text: "你好".to_string(),
+            cursor: None,
+        });
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("你好".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(text.get(0).text(), Some("Hello, World你好"));
     }
 }
@@ -159,8 +159,10 @@ pub fn MenuItem(
 
     let onclick = {
         to_owned![click];
-        move |ev| {
-            focus.focus();
+        move |ev: MouseEvent| {
+            if !ev.default_prevented() {
+                focus.focus();
+            }
             if let Some(onclick) = &click {
                 onclick.call(Some(ev))
             }
@@ -17,7 +17,7 @@ pub fn NativeContainer(children: Element) -> Element {
 
     let onkeydown = move |e: KeyboardEvent| {
         let allowed_to_navigate = native_platform.navigation_mark.peek().allowed();
-        if e.key == Key::Tab && allowed_to_navigate {
+        if e.key == Key::Tab && allowed_to_navigate && !e.default_prevented() {
             if e.modifiers.contains(Modifiers::SHIFT) {
                 platform
                     .send(EventMessage::FocusPrevAccessibilityNode)
@@ -119,6 +119,11 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
 
     // Moves the Y axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // Touchpads start and end their scrolls without moving
+        if e.default_prevented() || e.get_delta_y() == 0.0 {
+            return;
+        }
+
         let speed_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
@@ -204,7 +209,7 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                 clicking_alt.set(true);
             }
             k => {
-                if !focus.is_focused() {
+                if !focus.is_focused() || e.default_prevented() {
                     return;
                 }
                 if !scroll_with_arrows
@@ -396,6 +401,8 @@ mod test {
             name: EventName::Wheel,
             scroll: (0., -300.).into(),
             cursor: (5., 5.).into(),
+            delta_mode: WheelDeltaMode::Pixel,
+            phase: TouchPhase::Moved,
         });
 
         utils.wait_for_update().await;
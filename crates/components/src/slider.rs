@@ -137,7 +137,9 @@ pub fn Slider(
         to_owned![onmoved];
         move |e: MouseEvent| {
             e.stop_propagation();
-            focus.focus();
+            if !e.default_prevented() {
+                focus.focus();
+            }
             clicking.set(true);
             let coordinates = e.get_element_coordinates();
             let x = coordinates.x - 6.0;
@@ -153,6 +155,10 @@ pub fn Slider(
     };
 
     let onwheel = move |e: WheelEvent| {
+        // Touchpads start and end their scrolls without moving
+        if e.default_prevented() || e.get_delta_y() == 0.0 {
+            return;
+        }
         e.stop_propagation();
         let wheel_y = e.get_delta_y().clamp(-1.0, 1.0);
         let percentage = value + (wheel_y * 2.0);
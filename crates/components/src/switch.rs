@@ -103,7 +103,9 @@ pub fn Switch(props: SwitchProps) -> Element {
 
     let onclick = move |e: MouseEvent| {
         e.stop_propagation();
-        focus.focus();
+        if !e.default_prevented() {
+            focus.focus();
+        }
         props.ontoggled.call(());
     };
 
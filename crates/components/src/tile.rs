@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
-use freya_elements::elements as dioxus_elements;
+use freya_elements::{
+    elements as dioxus_elements,
+    events::MouseEvent,
+};
 use freya_hooks::{
     use_applied_theme,
     use_focus,
@@ -51,8 +54,10 @@ pub fn Tile(
         }
     });
 
-    let onclick = move |_| {
-        focus.focus();
+    let onclick = move |e: MouseEvent| {
+        if !e.default_prevented() {
+            focus.focus();
+        }
         if let Some(onselect) = &onselect {
             onselect.call(())
         }
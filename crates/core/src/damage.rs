@@ -0,0 +1,396 @@
+use freya_common::CachedParagraph;
+use freya_engine::prelude::*;
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use freya_node_state::{
+    FontStyleState,
+    OverflowMode,
+    ShadowPosition,
+    StyleState,
+    TransformState,
+};
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
+use torin::prelude::{
+    Area,
+    LayoutNode,
+    Point2D,
+    Size2D,
+    Torin,
+};
+
+use crate::{
+    dom::{
+        DioxusDOM,
+        DioxusNode,
+        FreyaDOM,
+    },
+    render::traverse_visible_nodes,
+};
+
+/// More damaged areas than this are merged into a single one.
+const MAX_DAMAGED_AREAS: usize = 16;
+
+/// Areas of the window that must be repainted.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub enum Damage {
+    /// Nothing changed.
+    #[default]
+    None,
+    /// Only these areas changed.
+    Partial(Vec<Area>),
+    /// The whole window must be repainted.
+    Full,
+}
+
+impl Damage {
+    /// Mark the given area as damaged.
+    pub fn add(&mut self, area: Area) {
+        if area.is_empty() {
+            return;
+        }
+
+        match self {
+            Self::None => *self = Self::Partial(vec![area]),
+            Self::Partial(areas) => {
+                // Merge the areas that overlap so nothing is painted twice
+                let mut area = area;
+                while let Some(i) = areas.iter().position(|damaged| damaged.intersects(&area)) {
+                    area = area.union(&areas.swap_remove(i));
+                }
+                areas.push(area);
+
+                if areas.len() > MAX_DAMAGED_AREAS {
+                    let union = areas
+                        .iter()
+                        .fold(Area::zero(), |union, area| union.union(area));
+                    *areas = vec![union];
+                }
+            }
+            Self::Full => {}
+        }
+    }
+
+    /// Whether anything must be repainted at all.
+    pub fn is_none(&self) -> bool {
+        *self == Self::None
+    }
+
+    /// Whether the given area must be repainted.
+    pub fn intersects(&self, area: &Area) -> bool {
+        match self {
+            Self::None => false,
+            Self::Partial(areas) => areas.iter().any(|damaged| damaged.intersects(area)),
+            Self::Full => true,
+        }
+    }
+
+    /// The damaged areas, empty when the whole window is damaged.
+    pub fn areas(&self) -> &[Area] {
+        match self {
+            Self::Partial(areas) => areas,
+            _ => &[],
+        }
+    }
+}
+
+/// Keeps track of what changed since the last frame, so only what changed is repainted.
+pub struct DamageTracker {
+    /// Nodes whose states changed since the last frame.
+    dirty_nodes: FxHashSet<NodeId>,
+    /// Where each Node was painted in the last frame.
+    painted_areas: FxHashMap<NodeId, Area>,
+    /// Effects of the Nodes that were painted with any in the last frame.
+    subtree_effects: FxHashMap<NodeId, SubtreeEffects>,
+    /// Whether the whole window must be repainted in the next frame.
+    full: bool,
+}
+
+impl Default for DamageTracker {
+    fn default() -> Self {
+        Self {
+            dirty_nodes: FxHashSet::default(),
+            painted_areas: FxHashMap::default(),
+            subtree_effects: FxHashMap::default(),
+            full: true,
+        }
+    }
+}
+
+impl DamageTracker {
+    /// Mark the given Node as changed.
+    pub fn mark_dirty(&mut self, node_id: NodeId) {
+        self.dirty_nodes.insert(node_id);
+    }
+
+    /// Repaint the whole window in the next frame, e.g. after it gets resized.
+    pub fn mark_full(&mut self) {
+        self.full = true;
+    }
+
+    /// Where the given Node was painted in the last frame.
+    pub fn painted_area(&self, node_id: &NodeId) -> Option<&Area> {
+        self.painted_areas.get(node_id)
+    }
+}
+
+/// Effects of a Node that are also applied when painting all its descendants.
+#[derive(Clone, Default, PartialEq)]
+struct SubtreeEffects {
+    opacity: Option<f32>,
+    blur: Option<f32>,
+    overflow: OverflowMode,
+    transform: TransformState,
+}
+
+impl SubtreeEffects {
+    fn new(node: &DioxusNode) -> Self {
+        let node_style = node.get::<StyleState>().unwrap();
+        let node_transform = node.get::<TransformState>().unwrap();
+
+        Self {
+            opacity: node_style.opacity,
+            blur: node_style.blur,
+            overflow: node_style.overflow.clone(),
+            transform: node_transform.clone(),
+        }
+    }
+}
+
+/// Effects that a Node inherits from its ancestors and that change where it's painted.
+#[derive(Clone, Copy, Default)]
+struct InheritedEffects {
+    matrix: Option<Matrix>,
+    blur_extent: f32,
+}
+
+fn inherited_effects(
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    node_id: NodeId,
+    scale_factor: f32,
+    effects: &mut FxHashMap<NodeId, InheritedEffects>,
+) -> InheritedEffects {
+    if let Some(node_effects) = effects.get(&node_id) {
+        return *node_effects;
+    }
+
+    let Some(node) = rdom.get(node_id) else {
+        return InheritedEffects::default();
+    };
+    let parent_effects = node
+        .parent_id()
+        .map(|parent_id| inherited_effects(rdom, layout, parent_id, scale_factor, effects))
+        .unwrap_or_default();
+
+    let node_transform = node.get::<TransformState>().unwrap();
+    let node_style = node.get::<StyleState>().unwrap();
+
+    let own_matrix = layout
+        .get(node_id)
+        .and_then(|layout_node| node_transform.matrix(&layout_node.visible_area(), scale_factor));
+    let matrix = match (parent_effects.matrix, own_matrix) {
+        (Some(mut parent_matrix), Some(own_matrix)) => {
+            parent_matrix.pre_concat(&own_matrix);
+            Some(parent_matrix)
+        }
+        (parent_matrix, own_matrix) => parent_matrix.or(own_matrix),
+    };
+
+    // Blurs spread the painted pixels by up to three times their sigma
+    let blur_extent = parent_effects.blur_extent
+        + node_style
+            .blur
+            .map(|blur| blur * scale_factor * 3.0)
+            .unwrap_or_default();
+
+    let node_effects = InheritedEffects {
+        matrix,
+        blur_extent,
+    };
+    effects.insert(node_id, node_effects);
+
+    node_effects
+}
+
+/// Get the area of the window where the given Node is painted,
+/// including its borders, shadows, overflowing text and transformations.
+fn painted_area(
+    node: &DioxusNode,
+    layout_node: &LayoutNode,
+    layout: &Torin<NodeId>,
+    scale_factor: f32,
+    effects: &mut FxHashMap<NodeId, InheritedEffects>,
+) -> Area {
+    let node_effects = inherited_effects(node.real_dom(), layout, node.id(), scale_factor, effects);
+    let node_style = node.get::<StyleState>().unwrap();
+
+    let mut area = layout_node.visible_area();
+
+    // Text might overflow its area
+    if let Some(CachedParagraph(paragraph)) = layout_node
+        .data
+        .as_ref()
+        .and_then(|data| data.get::<CachedParagraph>())
+    {
+        area = area.inflate(
+            (paragraph.longest_line() - area.width()).max(0.0),
+            (paragraph.height() - area.height()).max(0.0),
+        );
+    }
+
+    let mut outset = node_style
+        .border
+        .sides()
+        .iter()
+        .map(|border| border.width)
+        .fold(0.0, f32::max);
+    for shadow in &node_style.shadows {
+        if shadow.position == ShadowPosition::Normal {
+            outset = outset
+                .max(shadow.x.abs().max(shadow.y.abs()) + shadow.blur.max(0.0) + shadow.spread);
+        }
+    }
+
+    // Text shadows and outlines are painted outside the glyphs
+    if layout_node.data.is_some() {
+        let text_spans = node.children();
+        let font_styles = node.get::<FontStyleState>().into_iter().chain(
+            text_spans
+                .iter()
+                .filter_map(|child| child.get::<FontStyleState>()),
+        );
+        for font_style in font_styles {
+            for text_shadow in &font_style.text_shadows {
+                outset = outset.max(
+                    text_shadow.offset.x.abs().max(text_shadow.offset.y.abs())
+                        + text_shadow.blur_sigma as f32 * 3.0,
+                );
+            }
+            if let Some(text_stroke) = font_style.text_stroke {
+                outset = outset.max(text_stroke.width / 2.0);
+            }
+        }
+    }
+
+    // One extra pixel for the antialiasing
+    let outset = outset * scale_factor + node_effects.blur_extent + 1.0;
+    let area = area.inflate(outset, outset);
+
+    let area = if let Some(matrix) = node_effects.matrix {
+        let (rect, _) = matrix.map_rect(Rect::new(
+            area.min_x(),
+            area.min_y(),
+            area.max_x(),
+            area.max_y(),
+        ));
+        Area::new(
+            Point2D::new(rect.left, rect.top),
+            Size2D::new(rect.width(), rect.height()),
+        )
+    } else {
+        area
+    };
+
+    area.round_out()
+}
+
+/// Compute what areas of the window must be repainted,
+/// comparing where the Nodes are painted now with where they were painted in the last frame.
+pub fn process_damage(fdom: &FreyaDOM, scale_factor: f32) -> Damage {
+    let layout = fdom.layout();
+    let rdom = fdom.rdom();
+    let mut damage_tracker = fdom.damage_tracker();
+
+    let mut effects = FxHashMap::default();
+    let mut painted_areas = FxHashMap::default();
+    let mut subtree_effects = FxHashMap::default();
+    let mut backdrop_areas = Vec::new();
+
+    traverse_visible_nodes(fdom, &layout, |node_id, layout_node| {
+        let node = rdom.get(*node_id).unwrap();
+        let area = painted_area(&node, layout_node, &layout, scale_factor, &mut effects);
+
+        if node.get::<StyleState>().unwrap().backdrop_blur.is_some() {
+            backdrop_areas.push(area);
+        }
+
+        painted_areas.insert(*node_id, area);
+
+        let node_subtree_effects = SubtreeEffects::new(&node);
+        if node_subtree_effects != SubtreeEffects::default() {
+            subtree_effects.insert(*node_id, node_subtree_effects);
+        }
+    });
+
+    let mut damage = Damage::None;
+
+    if damage_tracker.full {
+        damage = Damage::Full;
+    } else {
+        // Nodes that were moved, resized, added or removed
+        for (node_id, area) in &painted_areas {
+            match damage_tracker.painted_areas.get(node_id) {
+                Some(previous_area) if previous_area == area => {}
+                previous_area => {
+                    if let Some(previous_area) = previous_area {
+                        damage.add(*previous_area);
+                    }
+                    damage.add(*area);
+                }
+            }
+        }
+        for (node_id, previous_area) in &damage_tracker.painted_areas {
+            if !painted_areas.contains_key(node_id) {
+                damage.add(*previous_area);
+            }
+        }
+
+        // Nodes whose states changed, text nodes are painted by their nearest painted ancestor
+        for node_id in &damage_tracker.dirty_nodes {
+            let mut node_id = Some(*node_id);
+            while let Some(id) = node_id {
+                if let Some(area) = painted_areas.get(&id) {
+                    damage.add(*area);
+                    break;
+                }
+                node_id = rdom.get(id).and_then(|node| node.parent_id());
+            }
+        }
+
+        // Descendants are painted with the effects of their ancestors, even when they overflow them
+        for node_id in &damage_tracker.dirty_nodes {
+            if subtree_effects.get(node_id) == damage_tracker.subtree_effects.get(node_id) {
+                continue;
+            }
+
+            let mut stack = vec![*node_id];
+            while let Some(id) = stack.pop() {
+                if let Some(area) = painted_areas.get(&id) {
+                    damage.add(*area);
+                }
+                if let Some(node) = rdom.get(id) {
+                    stack.extend(node.child_ids());
+                }
+            }
+        }
+
+        // Backdrop blurs depend on what is painted behind them
+        for area in backdrop_areas {
+            if damage.intersects(&area) {
+                damage.add(area);
+            }
+        }
+    }
+
+    damage_tracker.full = false;
+    damage_tracker.dirty_nodes.clear();
+    damage_tracker.painted_areas = painted_areas;
+    damage_tracker.subtree_effects = subtree_effects;
+
+    damage
+}
@@ -51,13 +51,22 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             minimum_height: layout.minimum_height,
             maximum_width: layout.maximum_width,
             maximum_height: layout.maximum_height,
+            aspect_ratio: layout.aspect_ratio,
             direction: layout.direction,
+            wrap: layout.wrap,
+            grid_columns: layout.grid_columns,
+            grid_rows: layout.grid_rows,
+            grid_column: layout.grid_column,
+            grid_row: layout.grid_row,
             padding: layout.padding,
             margin: layout.margin,
+            spacing: layout.spacing,
+            flex_shrink: layout.flex_shrink,
             main_alignment: layout.main_alignment,
             cross_alignment: layout.cross_alignment,
             offset_x: layout.offset_x,
             offset_y: layout.offset_y,
+            scrollable: layout.scrollable,
             has_layout_references: layout.node_ref.is_some(),
             position: layout.position,
             content: layout.content,
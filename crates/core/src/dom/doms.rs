@@ -8,22 +8,27 @@ use dioxus_core::VirtualDom;
 use freya_common::{
     Layers,
     ParagraphElements,
+    ReferencedElements,
     TextGroupMeasurement,
 };
+use freya_engine::prelude::FontCollection;
 use freya_native_core::{
     prelude::{
         DioxusState,
         State,
     },
     real_dom::{
+        NodeImmutable,
         NodeRef,
         RealDom,
     },
+    tags::TagName,
     NodeId,
     SendAnyMap,
 };
 use freya_node_state::{
     AccessibilityNodeState,
+    AttributesBytes,
     CursorState,
     CustomAttributeValues,
     FontStyleState,
@@ -34,11 +39,23 @@ use freya_node_state::{
     TransformState,
     ViewportState,
 };
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
 use torin::prelude::*;
 use tracing::info;
 
 use super::mutations_writer::MutationsWriter;
-use crate::prelude::measure_paragraph;
+use crate::{
+    damage::DamageTracker,
+    prelude::{
+        measure_image_bytes,
+        measure_paragraph,
+        measure_paragraph_cursor_area,
+        MeasuredImage,
+    },
+};
 
 pub type DioxusDOM = RealDom<CustomAttributeValues>;
 pub type DioxusNode<'a> = NodeRef<'a, CustomAttributeValues>;
@@ -119,6 +136,10 @@ pub struct FreyaDOM {
     torin: Arc<Mutex<Torin<NodeId>>>,
     paragraphs: ParagraphElements,
     layers: Layers,
+    referenced_elements: ReferencedElements,
+    /// Bytes of the image references last checked for a new image size.
+    checked_image_bytes: Mutex<FxHashMap<NodeId, Option<AttributesBytes>>>,
+    damage_tracker: Mutex<DamageTracker>,
 }
 
 impl Default for FreyaDOM {
@@ -141,6 +162,9 @@ impl Default for FreyaDOM {
             torin: Arc::new(Mutex::new(Torin::new())),
             paragraphs: ParagraphElements::default(),
             layers: Layers::default(),
+            referenced_elements: ReferencedElements::default(),
+            checked_image_bytes: Mutex::default(),
+            damage_tracker: Mutex::default(),
         }
     }
 }
@@ -158,6 +182,10 @@ impl FreyaDOM {
         &self.paragraphs
     }
 
+    pub fn damage_tracker(&self) -> MutexGuard<DamageTracker> {
+        self.damage_tracker.lock().unwrap()
+    }
+
     /// Create the initial DOM from the given Mutations
     pub fn init_dom(&mut self, vdom: &mut VirtualDom, scale_factor: f32) {
         // Build the RealDOM
@@ -175,6 +203,7 @@ impl FreyaDOM {
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
         ctx.insert(self.paragraphs.clone());
+        ctx.insert(self.referenced_elements.clone());
 
         self.rdom.update_state(ctx);
     }
@@ -197,9 +226,21 @@ impl FreyaDOM {
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
         ctx.insert(self.paragraphs.clone());
+        ctx.insert(self.referenced_elements.clone());
 
         // Update the Node's states
-        let (_, diff) = self.rdom.update_state(ctx);
+        let (dirty, diff) = self.rdom.update_state(ctx);
+
+        // Track what Nodes changed so they get repainted
+        {
+            let mut damage_tracker = self.damage_tracker();
+            for node_id in dirty.iter() {
+                damage_tracker.mark_dirty(*node_id);
+            }
+            for node_id in diff.keys() {
+                damage_tracker.mark_dirty(*node_id);
+            }
+        }
 
         let must_repaint = !diff.is_empty();
         let must_relayout = !self.layout().get_dirty_nodes().is_empty();
@@ -214,6 +255,24 @@ impl FreyaDOM {
         (must_repaint, must_relayout)
     }
 
+    /// Get the elements with an image or canvas reference that are still in the DOM.
+    fn referenced_elements(&self) -> MutexGuard<FxHashSet<NodeId>> {
+        let mut referenced_elements = self.referenced_elements.elements();
+        referenced_elements.retain(|node_id| self.rdom.get(*node_id).is_some());
+        referenced_elements
+    }
+
+    /// Mark the elements with an image or canvas reference as changed,
+    /// as their content might change without any of their states changing, e.g. the frames of a camera.
+    pub fn damage_referenced_elements(&self) {
+        let referenced_elements = self.referenced_elements();
+        let mut damage_tracker = self.damage_tracker();
+
+        for node_id in referenced_elements.iter() {
+            damage_tracker.mark_dirty(*node_id);
+        }
+    }
+
     /// Get a reference to the [`DioxusDOM`].
     pub fn rdom(&self) -> &DioxusDOM {
         &self.rdom
@@ -244,4 +303,103 @@ impl FreyaDOM {
             }
         }
     }
+
+    /// Measure the area of the cursor of the paragraph being edited, if any.
+    pub fn measure_ime_cursor_area(
+        &self,
+        font_collection: &FontCollection,
+        default_fonts: &[String],
+        scale_factor: f32,
+    ) -> Option<Area> {
+        let paragraphs = self.paragraphs.paragraphs();
+        let layout = self.layout();
+        paragraphs.values().flatten().find_map(|node_id| {
+            let node = self.rdom().get(*node_id)?;
+            let layout_node = layout.get(*node_id)?;
+            measure_paragraph_cursor_area(
+                &node,
+                layout_node,
+                font_collection,
+                default_fonts,
+                scale_factor,
+            )
+        })
+    }
+
+    /// Invalidate the layout of the `auto` sized images whose [`ImageReference`](freya_node_state::ImageReference)
+    /// now holds an image of a different size than the one they were measured with.
+    /// Returns whether any layout was invalidated.
+    pub fn invalidate_image_references(&self) -> bool {
+        let referenced_elements = self.referenced_elements();
+        let mut checked_image_bytes = self.checked_image_bytes.lock().unwrap();
+        let mut layout = self.layout();
+        let mut invalidated = false;
+
+        // Forget the elements that no longer have a reference
+        checked_image_bytes.retain(|node_id, _| referenced_elements.contains(node_id));
+
+        for node_id in referenced_elements.iter() {
+            let node = self.rdom.get(*node_id).unwrap();
+            if node.node_type().tag() != Some(&TagName::Image) {
+                continue;
+            }
+
+            let node_references = node.get::<ReferencesState>().unwrap();
+            let Some(image_ref) = &node_references.image_ref else {
+                continue;
+            };
+
+            let node_layout = node.get::<LayoutState>().unwrap();
+            if !node_layout.width.inner_sized() && !node_layout.height.inner_sized() {
+                continue;
+            }
+
+            let Some(layout_node) = layout.get(*node_id) else {
+                continue;
+            };
+            let measured_image = layout_node
+                .data
+                .as_ref()
+                .and_then(|data| data.get::<MeasuredImage>());
+
+            let image_data = image_ref
+                .0
+                .lock()
+                .unwrap()
+                .clone()
+                .map(AttributesBytes::Dynamic);
+            let is_same_bytes = |bytes: Option<&[u8]>| match (&image_data, bytes) {
+                (Some(image_data), Some(bytes)) => std::ptr::eq(image_data.as_slice(), bytes),
+                (None, None) => true,
+                _ => false,
+            };
+
+            // Same bytes the image was measured with or that were already checked
+            let measured_bytes = measured_image
+                .and_then(|image| image.bytes.as_ref())
+                .map(|bytes| bytes.as_slice());
+            let checked_bytes = checked_image_bytes.get(node_id);
+            if is_same_bytes(measured_bytes)
+                || checked_bytes.is_some_and(|bytes| {
+                    is_same_bytes(bytes.as_ref().map(AttributesBytes::as_slice))
+                })
+            {
+                continue;
+            }
+
+            checked_image_bytes.insert(*node_id, image_data.clone());
+
+            let image_size = image_data
+                .as_ref()
+                .and_then(measure_image_bytes)
+                .filter(|size| !size.is_empty());
+
+            if image_size != measured_image.map(|image| image.size) {
+                layout.invalidate(*node_id);
+                invalidated = true;
+            }
+        }
+
+        invalidated
+    }
 }
@@ -7,11 +7,14 @@ use freya_elements::{
     elements::PlatformEventData,
     events::{
         pointer::PointerType,
+        CompositionData,
         FileData,
+        FocusData,
         KeyboardData,
         MouseData,
         PointerData,
         TouchData,
+        TouchpadData,
         WheelData,
     },
 };
@@ -20,6 +23,7 @@ use torin::prelude::*;
 
 use super::event_name::EventName;
 use crate::{
+    accessibility::ACCESSIBILITY_ROOT_ID,
     events::PlatformEvent,
     prelude::PotentialEvent,
 };
@@ -54,6 +58,7 @@ impl DomEvent {
             node_id,
             layer,
             event,
+            default_action,
         }: PotentialEvent,
         node_area: Option<Area>,
         scale_factor: f64,
@@ -63,7 +68,21 @@ impl DomEvent {
         let bubbles = name.does_bubble();
 
         match event {
-            PlatformEvent::Mouse { cursor, button, .. } => {
+            PlatformEvent::Mouse { .. } | PlatformEvent::MouseClick { .. } => {
+                let (cursor, button, click_count) = match event {
+                    PlatformEvent::MouseClick {
+                        cursor,
+                        button,
+                        click_count,
+                        ..
+                    } => (cursor, Some(button), click_count),
+                    // Mouse buttons count as a single click unless told otherwise
+                    PlatformEvent::Mouse { cursor, button, .. } => {
+                        (cursor, button, u32::from(button.is_some()))
+                    }
+                    _ => unreachable!(),
+                };
+
                 let screen_coordinates = cursor / scale_factor;
                 let element_x =
                     (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
@@ -71,19 +90,26 @@ impl DomEvent {
                     (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
 
                 let event_data = if name.is_pointer() {
-                    DomEventData::Pointer(PointerData::new(
-                        screen_coordinates,
-                        (element_x, element_y).into(),
-                        PointerType::Mouse {
-                            trigger_button: button,
-                        },
-                    ))
+                    DomEventData::Pointer(
+                        PointerData::new(
+                            screen_coordinates,
+                            (element_x, element_y).into(),
+                            PointerType::Mouse {
+                                trigger_button: button,
+                            },
+                        )
+                        .with_default_action(default_action),
+                    )
                 } else {
-                    DomEventData::Mouse(MouseData::new(
-                        screen_coordinates,
-                        (element_x, element_y).into(),
-                        button,
-                    ))
+                    DomEventData::Mouse(
+                        MouseData::new(
+                            screen_coordinates,
+                            (element_x, element_y).into(),
+                            button,
+                            click_count,
+                        )
+                        .with_default_action(default_action),
+                    )
                 };
 
                 Self {
@@ -94,13 +120,46 @@ impl DomEvent {
                     layer,
                 }
             }
-            PlatformEvent::Wheel { scroll, .. } => Self {
+            PlatformEvent::Wheel {
+                scroll,
+                delta_mode,
+                phase,
+                ..
+            } => Self {
                 node_id,
                 name,
-                data: DomEventData::Wheel(WheelData::new(scroll.x, scroll.y)),
+                data: DomEventData::Wheel(
+                    WheelData::new(scroll.x, scroll.y, delta_mode, phase)
+                        .with_default_action(default_action),
+                ),
                 bubbles,
                 layer,
             },
+            PlatformEvent::Touchpad {
+                cursor,
+                gesture,
+                phase,
+                ..
+            } => {
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    data: DomEventData::Touchpad(TouchpadData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        gesture,
+                        phase,
+                    )),
+                    bubbles,
+                    layer,
+                }
+            }
             PlatformEvent::Keyboard {
                 ref key,
                 code,
@@ -109,7 +168,10 @@ impl DomEvent {
             } => Self {
                 node_id,
                 name,
-                data: DomEventData::Keyboard(KeyboardData::new(key.clone(), code, modifiers)),
+                data: DomEventData::Keyboard(
+                    KeyboardData::new(key.clone(), code, modifiers)
+                        .with_default_action(default_action),
+                ),
                 bubbles,
                 layer,
             },
@@ -124,23 +186,29 @@ impl DomEvent {
                 let element_y = location.y - node_area.unwrap_or_default().min_y() as f64;
 
                 let event_data = if name.is_pointer() {
-                    DomEventData::Pointer(PointerData::new(
-                        location,
-                        (element_x, element_y).into(),
-                        PointerType::Touch {
+                    DomEventData::Pointer(
+                        PointerData::new(
+                            location,
+                            (element_x, element_y).into(),
+                            PointerType::Touch {
+                                finger_id,
+                                phase,
+                                force,
+                            },
+                        )
+                        .with_default_action(default_action),
+                    )
+                } else {
+                    DomEventData::Touch(
+                        TouchData::new(
+                            location,
+                            (element_x, element_y).into(),
                             finger_id,
                             phase,
                             force,
-                        },
-                    ))
-                } else {
-                    DomEventData::Touch(TouchData::new(
-                        location,
-                        (element_x, element_y).into(),
-                        finger_id,
-                        phase,
-                        force,
-                    ))
+                        )
+                        .with_default_action(default_action),
+                    )
                 };
 
                 Self {
@@ -164,6 +232,28 @@ impl DomEvent {
                     layer,
                 }
             }
+            PlatformEvent::Focus { previous, next, .. } => {
+                // The root means that no Node is focused
+                let focused_node = |id| (id != ACCESSIBILITY_ROOT_ID).then_some(id);
+
+                Self {
+                    node_id,
+                    name,
+                    data: DomEventData::Focus(FocusData::new(
+                        focused_node(previous),
+                        focused_node(next),
+                    )),
+                    bubbles,
+                    layer,
+                }
+            }
+            PlatformEvent::Composition { text, cursor, .. } => Self {
+                node_id,
+                name,
+                data: DomEventData::Composition(CompositionData::new(text, cursor)),
+                bubbles,
+                layer,
+            },
         }
     }
 }
@@ -175,8 +265,11 @@ pub enum DomEventData {
     Keyboard(KeyboardData),
     Wheel(WheelData),
     Touch(TouchData),
+    Touchpad(TouchpadData),
     Pointer(PointerData),
     File(FileData),
+    Focus(FocusData),
+    Composition(CompositionData),
 }
 
 impl DomEventData {
@@ -186,8 +279,11 @@ impl DomEventData {
             DomEventData::Keyboard(k) => Rc::new(PlatformEventData::new(Box::new(k))),
             DomEventData::Wheel(w) => Rc::new(PlatformEventData::new(Box::new(w))),
             DomEventData::Touch(t) => Rc::new(PlatformEventData::new(Box::new(t))),
+            DomEventData::Touchpad(t) => Rc::new(PlatformEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(PlatformEventData::new(Box::new(p))),
             DomEventData::File(fd) => Rc::new(PlatformEventData::new(Box::new(fd))),
+            DomEventData::Focus(f) => Rc::new(PlatformEventData::new(Box::new(f))),
+            DomEventData::Composition(c) => Rc::new(PlatformEventData::new(Box::new(c))),
         }
     }
 }
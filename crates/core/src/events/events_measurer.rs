@@ -1,3 +1,4 @@
+use freya_elements::events::DefaultAction;
 use freya_engine::prelude::*;
 use freya_native_core::{
     real_dom::NodeImmutable,
@@ -5,11 +6,21 @@ use freya_native_core::{
     NodeId,
 };
 use freya_node_state::{
+    AccessibilityNodeState,
     Fill,
     StyleState,
+    TransformState,
     ViewportState,
 };
 use itertools::sorted;
+use rustc_hash::FxHashMap;
+use torin::{
+    prelude::{
+        CursorPoint,
+        Torin,
+    },
+    scaled::Scaled,
+};
 
 pub use crate::events::{
     DomEvent,
@@ -30,7 +41,7 @@ pub fn process_events(
     let global_events = measure_global_events(events);
 
     // 2. Get potential events that could be emitted based on the elements layout and viewports
-    let potential_events = measure_potential_event_listeners(events, dom);
+    let potential_events = measure_potential_event_listeners(events, dom, scale_factor);
 
     // 3. Get what events can be actually emitted based on what elements are listening
     let dom_events = measure_dom_events(potential_events, dom, scale_factor);
@@ -45,9 +56,10 @@ pub fn process_events(
 
     let colateral_global_events = measure_colateral_global_events(&to_emit_dom_collateral_events);
 
-    // 6. Join both the dom and colateral dom events and sort them
+    // 6. Join the dom, colateral dom and focus events and sort them
     to_emit_dom_events.extend(to_emit_dom_collateral_events);
-    to_emit_dom_events.sort_unstable();
+    to_emit_dom_events.extend(measure_focus_events(events, dom, scale_factor));
+    to_emit_dom_events.sort();
 
     // 7. Emit the global events
     measure_global_events_listeners(
@@ -95,46 +107,92 @@ pub fn measure_global_events(events: &EventsQueue) -> Vec<PlatformEvent> {
 }
 
 /// Measure what potential event listeners could be triggered
-pub fn measure_potential_event_listeners(events: &EventsQueue, fdom: &FreyaDOM) -> PotentialEvents {
+pub fn measure_potential_event_listeners(
+    events: &EventsQueue,
+    fdom: &FreyaDOM,
+    scale_factor: f64,
+) -> PotentialEvents {
     let mut potential_events = PotentialEvents::default();
 
     let layout = fdom.layout();
     let rdom = fdom.rdom();
     let layers = fdom.layers();
+    let mut matrices = FxHashMap::default();
+
+    // Every event caused by the same platform event shares its default action
+    let default_actions = events
+        .iter()
+        .map(|_| DefaultAction::default())
+        .collect::<Vec<_>>();
 
     // Propagate events from the top to the bottom
     for (layer, layer_nodes) in sorted(layers.layers().iter()) {
         for node_id in layer_nodes {
             let layout_node = layout.get(*node_id);
             if let Some(layout_node) = layout_node {
-                'events: for event in events.iter() {
-                    if let PlatformEvent::Keyboard { name, .. } = event {
+                'events: for (event, default_action) in events.iter().zip(&default_actions) {
+                    // Keyboard and IME events are not tied to the cursor, so they reach every listener
+                    if matches!(
+                        event,
+                        PlatformEvent::Keyboard { .. } | PlatformEvent::Composition { .. }
+                    ) {
+                        let name = event.get_name();
                         let event_data = PotentialEvent {
                             node_id: *node_id,
                             layer: Some(*layer),
                             event: event.clone(),
+                            default_action: default_action.clone(),
                         };
-                        potential_events.entry(*name).or_default().push(event_data);
+                        potential_events.entry(name).or_default().push(event_data);
                     } else {
                         let data = match event {
                             PlatformEvent::Mouse { name, cursor, .. } => Some((name, cursor)),
+                            PlatformEvent::MouseClick { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::Wheel { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::Touch { name, location, .. } => Some((name, location)),
+                            PlatformEvent::Touchpad { name, cursor, .. } => Some((name, cursor)),
                             PlatformEvent::File { name, cursor, .. } => Some((name, cursor)),
                             _ => None,
                         };
                         if let Some((name, cursor)) = data {
-                            let cursor_is_inside = layout_node.area.contains(cursor.to_f32());
+                            // Map the cursor into the coordinates of the Node before its transformations
+                            let Some(node_cursor) = local_cursor(
+                                rdom,
+                                &layout,
+                                *node_id,
+                                cursor,
+                                scale_factor as f32,
+                                &mut matrices,
+                            ) else {
+                                continue;
+                            };
+
+                            let cursor_is_inside = layout_node.area.contains(node_cursor.to_f32());
 
                             // Make sure the cursor is inside the node area
                             if cursor_is_inside {
                                 let node = rdom.get(*node_id).unwrap();
                                 let node_viewports = node.get::<ViewportState>().unwrap();
 
-                                // Make sure the cursor is inside all the applicable viewports from the element
+                                // Make sure the cursor is inside all the applicable viewports from the element,
+                                // excluding what is clipped by their rounded corners
                                 for viewport_id in &node_viewports.viewports {
                                     let viewport = layout.get(*viewport_id).unwrap().visible_area();
-                                    if !viewport.contains(cursor.to_f32()) {
+                                    let viewport_node = rdom.get(*viewport_id).unwrap();
+                                    let mut corner_radius =
+                                        viewport_node.get::<StyleState>().unwrap().corner_radius;
+                                    corner_radius.scale(scale_factor as f32);
+                                    let viewport_cursor = local_cursor(
+                                        rdom,
+                                        &layout,
+                                        *viewport_id,
+                                        cursor,
+                                        scale_factor as f32,
+                                        &mut matrices,
+                                    );
+                                    if !viewport_cursor.is_some_and(|viewport_cursor| {
+                                        corner_radius.contains(&viewport, viewport_cursor.to_f32())
+                                    }) {
                                         continue 'events;
                                     }
                                 }
@@ -143,6 +201,7 @@ pub fn measure_potential_event_listeners(events: &EventsQueue, fdom: &FreyaDOM)
                                     node_id: *node_id,
                                     layer: Some(*layer),
                                     event: event.clone(),
+                                    default_action: default_action.clone(),
                                 };
 
                                 potential_events
@@ -160,6 +219,61 @@ pub fn measure_potential_event_listeners(events: &EventsQueue, fdom: &FreyaDOM)
     potential_events
 }
 
+/// Get the matrix of all the transformations applied to the given Node, including those inherited from its ancestors.
+fn node_matrix(
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    node_id: NodeId,
+    scale_factor: f32,
+    matrices: &mut FxHashMap<NodeId, Option<Matrix>>,
+) -> Option<Matrix> {
+    if let Some(matrix) = matrices.get(&node_id) {
+        return *matrix;
+    }
+
+    let node = rdom.get(node_id)?;
+    let parent_matrix = node
+        .parent_id()
+        .and_then(|parent_id| node_matrix(rdom, layout, parent_id, scale_factor, matrices));
+    let node_transform = node.get::<TransformState>().unwrap();
+    let own_matrix = layout
+        .get(node_id)
+        .and_then(|layout_node| node_transform.matrix(&layout_node.visible_area(), scale_factor));
+
+    let matrix = match (parent_matrix, own_matrix) {
+        (Some(mut parent_matrix), Some(own_matrix)) => {
+            parent_matrix.pre_concat(&own_matrix);
+            Some(parent_matrix)
+        }
+        (parent_matrix, own_matrix) => parent_matrix.or(own_matrix),
+    };
+
+    matrices.insert(node_id, matrix);
+
+    matrix
+}
+
+/// Map the cursor into the untransformed coordinates of the given Node.
+/// Returns `None` if the Node transformations can't be inverted, e.g `scale="0"`.
+fn local_cursor(
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    node_id: NodeId,
+    cursor: &CursorPoint,
+    scale_factor: f32,
+    matrices: &mut FxHashMap<NodeId, Option<Matrix>>,
+) -> Option<CursorPoint> {
+    let Some(matrix) = node_matrix(rdom, layout, node_id, scale_factor, matrices) else {
+        return Some(*cursor);
+    };
+
+    let point = matrix
+        .invert()?
+        .map_point((cursor.x as f32, cursor.y as f32));
+
+    Some(CursorPoint::new(point.x as f64, point.y as f64))
+}
+
 fn is_node_parent_of(rdom: &DioxusDOM, node: NodeId, parent_node: NodeId) -> bool {
     let mut stack = vec![parent_node];
     while let Some(id) = stack.pop() {
@@ -179,6 +293,39 @@ fn is_node_parent_of(rdom: &DioxusDOM, node: NodeId, parent_node: NodeId) -> boo
     false
 }
 
+/// Measure what nodes are listening to a capture-phase event, ordered from the root to the bottom.
+/// Keyboard events are captured by every listener, the rest only by the ancestors of the top-most node.
+fn measure_capture_listeners(
+    rdom: &DioxusDOM,
+    event_nodes: &[PotentialEvent],
+    capture_event: EventName,
+) -> Vec<PotentialEvent> {
+    let target = event_nodes
+        .last()
+        .map(|potential_event| potential_event.node_id);
+
+    let mut listeners = event_nodes
+        .iter()
+        .filter(|PotentialEvent { node_id, .. }| {
+            rdom.is_node_listening(node_id, &capture_event)
+                && (capture_event.does_go_through_solid()
+                    || target.is_some_and(|target| {
+                        target == *node_id || is_node_parent_of(rdom, target, *node_id)
+                    }))
+        })
+        .map(|potential_event| {
+            let mut potential_event = potential_event.clone();
+            potential_event.event.set_name(capture_event);
+            potential_event
+        })
+        .collect::<Vec<_>>();
+
+    listeners
+        .sort_by_key(|PotentialEvent { node_id, .. }| rdom.get(*node_id).map(|node| node.height()));
+
+    listeners
+}
+
 /// Measure what DOM events could be emitted
 fn measure_dom_events(
     potential_events: PotentialEvents,
@@ -196,6 +343,15 @@ fn measure_dom_events(
 
         // Iterate over the collateral events (including the source)
         'event: for collateral_event in collateral_events {
+            if collateral_event.is_capture() {
+                valid_events.extend(measure_capture_listeners(
+                    rdom,
+                    &event_nodes,
+                    collateral_event,
+                ));
+                continue;
+            }
+
             let mut child_node: Option<NodeId> = None;
 
             // Iterate over the event nodes
@@ -203,6 +359,7 @@ fn measure_dom_events(
                 node_id,
                 event,
                 layer,
+                default_action,
             } in event_nodes.iter().rev()
             {
                 let Some(node) = rdom.get(*node_id) else {
@@ -223,6 +380,7 @@ fn measure_dom_events(
                             node_id: *node_id,
                             event: valid_event,
                             layer: *layer,
+                            default_action: default_action.clone(),
                         });
 
                         // Stack events that do not bubble up
@@ -262,6 +420,81 @@ fn measure_dom_events(
     new_events
 }
 
+/// Find the Node with the given accessibility ID.
+fn find_accessibility_node(rdom: &DioxusDOM, accessibility_id: AccessibilityId) -> Option<NodeId> {
+    let mut found_node = None;
+
+    rdom.traverse_depth_first_advanced(|node| {
+        if found_node.is_some() || !node.node_type().is_element() {
+            return false;
+        }
+
+        let node_accessibility = node.get::<AccessibilityNodeState>().unwrap();
+        if node_accessibility.accessibility_id == Some(accessibility_id) {
+            found_node = Some(node.id());
+        }
+
+        found_node.is_none()
+    });
+
+    found_node
+}
+
+/// Measure what Nodes must be notified of focus changes.
+/// `focus` and `blur` are only emitted to the Node that gained or lost the focus,
+/// `focusin` and `focusout` bubble from it.
+fn measure_focus_events(events: &EventsQueue, fdom: &FreyaDOM, scale_factor: f64) -> Vec<DomEvent> {
+    let rdom = fdom.rdom();
+    let mut focus_events = Vec::new();
+
+    for event in events {
+        let PlatformEvent::Focus {
+            name,
+            previous,
+            next,
+        } = event
+        else {
+            continue;
+        };
+
+        let accessibility_id = if matches!(name, EventName::Blur | EventName::FocusOut) {
+            previous
+        } else {
+            next
+        };
+
+        let Some(target_id) = find_accessibility_node(rdom, *accessibility_id) else {
+            continue;
+        };
+
+        // Emit the event in the closest Node listening to it, it will bubble from there if possible
+        let mut node_id = Some(target_id);
+        while let Some(id) = node_id {
+            if rdom.is_node_listening(&id, name) {
+                focus_events.push(DomEvent::new(
+                    PotentialEvent {
+                        node_id: id,
+                        layer: None,
+                        event: event.clone(),
+                        default_action: DefaultAction::default(),
+                    },
+                    None,
+                    scale_factor,
+                ));
+                break;
+            }
+
+            if !name.does_bubble() {
+                break;
+            }
+
+            node_id = rdom.get(id).and_then(|node| node.parent_id());
+        }
+    }
+
+    focus_events
+}
+
 /// Emit global events
 fn measure_global_events_listeners(
     global_events: Vec<PlatformEvent>,
@@ -280,6 +513,7 @@ fn measure_global_events_listeners(
                     node_id: listener.id(),
                     layer: None,
                     event: global_event.clone(),
+                    default_action: DefaultAction::default(),
                 },
                 None,
                 scale_factor,
@@ -1,5 +1,6 @@
 #![allow(clippy::type_complexity)]
 
+use freya_elements::events::DefaultAction;
 use freya_native_core::NodeId;
 use rustc_hash::FxHashMap;
 
@@ -56,6 +57,7 @@ impl NodesState {
                             cursor,
                             button,
                         },
+                        default_action: DefaultAction::default(),
                     });
 
                     // Remove the node from the list of hovered nodes as now, the cursor has left
@@ -1,9 +1,13 @@
 use std::path::PathBuf;
 
-use freya_elements::events::keyboard::{
-    Code,
-    Key,
-    Modifiers,
+use freya_elements::events::{
+    keyboard::{
+        Code,
+        Key,
+        Modifiers,
+    },
+    TouchpadGesture,
+    WheelDeltaMode,
 };
 use torin::prelude::*;
 use winit::event::{
@@ -12,7 +16,11 @@ use winit::event::{
     TouchPhase,
 };
 
-use crate::prelude::EventName;
+use crate::{
+    accessibility::ACCESSIBILITY_ROOT_ID,
+    prelude::EventName,
+    types::AccessibilityId,
+};
 
 /// Events emitted in Freya.
 #[derive(Clone, Debug)]
@@ -23,11 +31,22 @@ pub enum PlatformEvent {
         cursor: CursorPoint,
         button: Option<MouseButton>,
     },
+    /// A Mouse Event of a button that tracks its consecutive clicks.
+    /// Plain [PlatformEvent::Mouse] button events count as a single click.
+    MouseClick {
+        name: EventName,
+        cursor: CursorPoint,
+        button: MouseButton,
+        /// Number of consecutive clicks of `button`.
+        click_count: u32,
+    },
     /// A Wheel event.
     Wheel {
         name: EventName,
         scroll: CursorPoint,
         cursor: CursorPoint,
+        delta_mode: WheelDeltaMode,
+        phase: TouchPhase,
     },
     /// A Keyboard event.
     Keyboard {
@@ -44,32 +63,90 @@ pub enum PlatformEvent {
         phase: TouchPhase,
         force: Option<Force>,
     },
+    /// A Touchpad gesture event.
+    Touchpad {
+        name: EventName,
+        cursor: CursorPoint,
+        gesture: TouchpadGesture,
+        phase: TouchPhase,
+    },
     /// A File event.
     File {
         name: EventName,
         cursor: CursorPoint,
         file_path: Option<PathBuf>,
     },
+    /// A Focus event.
+    Focus {
+        name: EventName,
+        previous: AccessibilityId,
+        next: AccessibilityId,
+    },
+    /// An IME Composition event.
+    Composition {
+        name: EventName,
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
 }
 
 impl PlatformEvent {
     pub fn get_name(&self) -> EventName {
         match self {
             Self::Mouse { name, .. } => *name,
+            Self::MouseClick { name, .. } => *name,
             Self::Wheel { name, .. } => *name,
             Self::Keyboard { name, .. } => *name,
             Self::Touch { name, .. } => *name,
+            Self::Touchpad { name, .. } => *name,
             Self::File { name, .. } => *name,
+            Self::Focus { name, .. } => *name,
+            Self::Composition { name, .. } => *name,
         }
     }
 
     pub fn set_name(&mut self, new_name: EventName) {
         match self {
             Self::Mouse { name, .. } => *name = new_name,
+            Self::MouseClick { name, .. } => *name = new_name,
             Self::Wheel { name, .. } => *name = new_name,
             Self::Keyboard { name, .. } => *name = new_name,
             Self::Touch { name, .. } => *name = new_name,
+            Self::Touchpad { name, .. } => *name = new_name,
             Self::File { name, .. } => *name = new_name,
+            Self::Focus { name, .. } => *name = new_name,
+            Self::Composition { name, .. } => *name = new_name,
+        }
+    }
+
+    /// Get the events caused by the focus moving from one Node to another.
+    pub fn focus_events(previous: AccessibilityId, next: AccessibilityId) -> Vec<Self> {
+        let mut events = Vec::new();
+
+        if previous == next {
+            return events;
         }
+
+        if previous != ACCESSIBILITY_ROOT_ID {
+            events.extend(
+                [EventName::Blur, EventName::FocusOut].map(|name| Self::Focus {
+                    name,
+                    previous,
+                    next,
+                }),
+            );
+        }
+
+        if next != ACCESSIBILITY_ROOT_ID {
+            events.extend(
+                [EventName::Focus, EventName::FocusIn].map(|name| Self::Focus {
+                    name,
+                    previous,
+                    next,
+                }),
+            );
+        }
+
+        events
     }
 }
@@ -1,3 +1,4 @@
+use freya_elements::events::DefaultAction;
 use freya_native_core::NodeId;
 
 use crate::prelude::PlatformEvent;
@@ -8,4 +9,6 @@ pub struct PotentialEvent {
     pub(crate) node_id: NodeId,
     pub(crate) event: PlatformEvent,
     pub(crate) layer: Option<i16>,
+    /// Shared by all the events caused by the same platform event.
+    pub(crate) default_action: DefaultAction,
 }
@@ -1,4 +1,5 @@
 pub mod accessibility;
+pub mod damage;
 pub mod dom;
 pub mod events;
 pub mod layout;
@@ -13,6 +14,7 @@ pub mod types;
 pub mod prelude {
     pub use crate::{
         accessibility::*,
+        damage::*,
         dom::*,
         events::*,
         layout::*,
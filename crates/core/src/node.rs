@@ -1,7 +1,7 @@
 use freya_engine::prelude::*;
 use freya_native_core::real_dom::NodeImmutable;
 use freya_node_state::{
-    Border,
+    Borders,
     CornerRadius,
     CursorState,
     Fill,
@@ -128,7 +128,9 @@ impl<'a> Iterator for NodeStateIterator<'a> {
                 let background = &self.state.style.background;
                 let fill = match *background {
                     Fill::Color(_) => AttributeType::Color(background.clone()),
-                    Fill::LinearGradient(_) => AttributeType::LinearGradient(background.clone()),
+                    Fill::LinearGradient(_) | Fill::RadialGradient(_) | Fill::ConicGradient(_) => {
+                        AttributeType::Gradient(background.clone())
+                    }
                 };
                 Some(("background", fill))
             }
@@ -197,7 +199,7 @@ impl<'a> Iterator for NodeStateIterator<'a> {
 
 pub enum AttributeType<'a> {
     Color(Fill),
-    LinearGradient(Fill),
+    Gradient(Fill),
     Size(&'a Size),
     Measure(f32),
     Measures(Gaps),
@@ -207,7 +209,7 @@ pub enum AttributeType<'a> {
     Shadow(&'a Shadow),
     TextShadow(&'a TextShadow),
     Text(String),
-    Border(&'a Border),
+    Border(&'a Borders),
     TextAlignment(&'a TextAlign),
     TextOverflow(&'a TextOverflow),
 }
@@ -6,7 +6,10 @@ use freya_native_core::NodeId;
 use torin::torin::Torin;
 use winit::window::Window;
 
-use crate::dom::FreyaDOM;
+use crate::{
+    damage::Damage,
+    dom::FreyaDOM,
+};
 
 /// Manages all loaded plugins.
 #[derive(Default)]
@@ -38,6 +41,9 @@ pub enum PluginEvent<'a> {
         freya_dom: &'a FreyaDOM,
     },
 
+    /// What areas of the window were repainted in this frame.
+    Damage(&'a Damage),
+
     /// After rendering the app to the Canvas.
     AfterRender {
         canvas: &'a Canvas,
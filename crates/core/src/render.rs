@@ -4,19 +4,23 @@ use freya_native_core::{
 };
 use freya_node_state::ViewportState;
 use itertools::sorted;
+use rustc_hash::FxHashSet;
 use torin::prelude::{
     LayoutNode,
     Torin,
 };
 
-use crate::dom::FreyaDOM;
+use crate::{
+    damage::Damage,
+    dom::FreyaDOM,
+};
 
-/// Call the render function for the nodes that should be rendered.
-pub fn process_render(
+/// Call the given function for the nodes that are visible, from the bottom layer to the top one.
+pub fn traverse_visible_nodes(
     fdom: &FreyaDOM,
-    mut render_fn: impl FnMut(&FreyaDOM, &NodeId, &LayoutNode, &Torin<NodeId>),
+    layout: &Torin<NodeId>,
+    mut f: impl FnMut(&NodeId, &LayoutNode),
 ) {
-    let layout = fdom.layout();
     let rdom = fdom.rdom();
     let layers = fdom.layers();
 
@@ -37,9 +41,58 @@ pub fn process_render(
                     }
                 }
 
-                // Render the element
-                render_fn(fdom, node_id, layout_node, &layout)
+                f(node_id, layout_node)
             }
         }
     }
 }
+
+/// Call the given function for the given Node and its descendants that are visible,
+/// from the bottom layer to the top one.
+pub fn traverse_visible_subtree(
+    fdom: &FreyaDOM,
+    layout: &Torin<NodeId>,
+    root_id: NodeId,
+    mut f: impl FnMut(&NodeId, &LayoutNode),
+) {
+    let rdom = fdom.rdom();
+
+    let mut subtree = FxHashSet::default();
+    let mut stack = vec![root_id];
+    while let Some(node_id) = stack.pop() {
+        if let Some(node) = rdom.get(node_id) {
+            stack.extend(node.child_ids());
+            subtree.insert(node_id);
+        }
+    }
+
+    traverse_visible_nodes(fdom, layout, |node_id, layout_node| {
+        if subtree.contains(node_id) {
+            f(node_id, layout_node)
+        }
+    });
+}
+
+/// Call the render function for the nodes that should be rendered.
+/// Nodes outside of the damaged areas are still passed, but marked as not damaged,
+/// so they can pass their effects (e.g. opacity) to their damaged descendants without being painted.
+pub fn process_render(
+    fdom: &FreyaDOM,
+    damage: &Damage,
+    mut render_fn: impl FnMut(&FreyaDOM, &NodeId, &LayoutNode, &Torin<NodeId>, bool),
+) {
+    let layout = fdom.layout();
+    let damage_tracker = fdom.damage_tracker();
+
+    traverse_visible_nodes(fdom, &layout, |node_id, layout_node| {
+        let is_damaged = match damage {
+            Damage::Full => true,
+            _ => damage_tracker
+                .painted_area(node_id)
+                .is_some_and(|area| damage.intersects(area)),
+        };
+
+        // Render the element
+        render_fn(fdom, node_id, layout_node, &layout, is_damaged)
+    });
+}
@@ -5,15 +5,20 @@ use freya_common::{
     CursorLayoutResponse,
     TextGroupMeasurement,
 };
+use freya_engine::prelude::*;
 use freya_native_core::prelude::NodeImmutable;
 use freya_node_state::CursorState;
 use torin::prelude::{
+    Area,
     CursorPoint,
     LayoutNode,
+    Size2D,
 };
 
 use crate::prelude::{
+    align_highlights_and_cursor_paragraph,
     align_main_align_paragraph,
+    create_paragraph,
     DioxusNode,
 };
 
@@ -82,3 +87,49 @@ pub fn measure_paragraph(
         }
     }
 }
+
+/// Measure the area of the cursor of an editable paragraph, or the whole paragraph if the cursor can't be measured.
+/// Returns `None` if the paragraph is not showing a cursor.
+pub fn measure_paragraph_cursor_area(
+    node: &DioxusNode,
+    layout_node: &LayoutNode,
+    font_collection: &FontCollection,
+    default_fonts: &[String],
+    scale_factor: f32,
+) -> Option<Area> {
+    let cursor_state = node.get::<CursorState>().unwrap();
+    let cursor_position = usize::try_from(cursor_state.position?).ok()?;
+
+    // Same paragraph as the one rendered while editing
+    let paragraph = create_paragraph(
+        node,
+        &layout_node.area.size,
+        font_collection,
+        true,
+        default_fonts,
+        scale_factor,
+    );
+
+    let cursor_rects = paragraph.get_rects_for_range(
+        cursor_position..cursor_position + 1,
+        RectHeightStyle::Tight,
+        RectWidthStyle::Tight,
+    );
+
+    let Some(cursor_rect) = cursor_rects.first() else {
+        return Some(layout_node.area);
+    };
+
+    let (start, end) = align_highlights_and_cursor_paragraph(
+        node,
+        &layout_node.area,
+        &paragraph,
+        cursor_rect,
+        Some(1.0),
+    );
+
+    Some(Area::new(
+        start,
+        Size2D::new(end.x - start.x, end.y - start.y),
+    ))
+}
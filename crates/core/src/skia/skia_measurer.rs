@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use freya_common::{
     CachedParagraph,
+    CachedStrokeParagraph,
     NodeReferenceLayout,
 };
 use freya_engine::prelude::*;
@@ -16,10 +17,13 @@ use freya_native_core::{
     NodeId,
 };
 use freya_node_state::{
+    AttributesBytes,
     CursorState,
     FontStyleState,
     HighlightMode,
     LayoutState,
+    ReferencesState,
+    StyleState,
     TextOverflow,
 };
 use torin::prelude::{
@@ -62,7 +66,7 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
     fn measure(
         &mut self,
         node_id: NodeId,
-        _node: &Node,
+        layout_node: &Node,
         area_size: &Size2D,
     ) -> Option<(Size2D, Arc<SendAnyMap>)> {
         let node = self.rdom.get(node_id).unwrap();
@@ -94,6 +98,53 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
                 let res = Size2D::new(paragraph.longest_line(), paragraph.height());
                 let mut map = SendAnyMap::new();
                 map.insert(CachedParagraph(paragraph));
+                if let Some(stroke_paragraph) = create_stroke_paragraph(
+                    &node,
+                    area_size,
+                    self.font_collection,
+                    false,
+                    self.default_fonts,
+                    self.scale_factor,
+                ) {
+                    map.insert(CachedStrokeParagraph(stroke_paragraph));
+                }
+                Some((res, Arc::new(map)))
+            }
+            NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Image => {
+                let width_inner = layout_node.width.inner_sized();
+                let height_inner = layout_node.height.inner_sized();
+
+                // The image is only decoded when the layout depends on its intrinsic size
+                if !width_inner && !height_inner {
+                    return None;
+                }
+
+                let image_bytes = image_bytes(&node);
+                let image_size = image_bytes.as_ref().and_then(measure_image_bytes)?;
+                if image_size.is_empty() {
+                    return None;
+                }
+
+                let mut map = SendAnyMap::new();
+                map.insert(MeasuredImage {
+                    bytes: image_bytes,
+                    size: image_size,
+                });
+
+                let image_size = image_size * self.scale_factor;
+
+                // Keep the aspect ratio of the image if only one of the axes is sized by it
+                let res = match (width_inner, height_inner) {
+                    (true, false) => Size2D::new(
+                        area_size.height * image_size.width / image_size.height,
+                        area_size.height,
+                    ),
+                    (false, true) => Size2D::new(
+                        area_size.width,
+                        area_size.width * image_size.height / image_size.width,
+                    ),
+                    _ => image_size,
+                };
                 Some((res, Arc::new(map)))
             }
             _ => None,
@@ -118,6 +169,7 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
             let mut node_layout = NodeReferenceLayout {
                 area: layout_node.area,
                 inner: layout_node.inner_sizes,
+                node_id: Some(node_id),
             };
             node_layout.div(self.scale_factor);
             reference.0.send(node_layout).ok();
@@ -158,6 +210,32 @@ pub fn create_label(
     paragraph
 }
 
+/// Image an `auto` sized `image` element was measured with.
+pub struct MeasuredImage {
+    /// Bytes of the image, kept so they can be compared by address.
+    pub bytes: Option<AttributesBytes>,
+    /// Intrinsic size of the image, not scaled.
+    pub size: Size2D,
+}
+
+/// Get the bytes of the image of an `image` element, from its reference if it has one.
+pub fn image_bytes(node: &DioxusNode) -> Option<AttributesBytes> {
+    let node_references = node.get::<ReferencesState>().unwrap();
+
+    if let Some(image_ref) = &node_references.image_ref {
+        let image_data = image_ref.0.lock().unwrap();
+        image_data.clone().map(AttributesBytes::Dynamic)
+    } else {
+        node.get::<StyleState>().unwrap().image_data.clone()
+    }
+}
+
+/// Get the intrinsic size of an encoded image
+pub fn measure_image_bytes(bytes: &AttributesBytes) -> Option<Size2D> {
+    Image::from_encoded(Data::new_copy(bytes.as_slice()))
+        .map(|image| Size2D::new(image.width() as f32, image.height() as f32))
+}
+
 /// Align the Y axis of the highlights and cursor of a paragraph
 pub fn align_highlights_and_cursor_paragraph(
     node: &DioxusNode,
@@ -208,6 +286,81 @@ pub fn create_paragraph(
     is_rendering: bool,
     default_font_family: &[String],
     scale_factor: f32,
+) -> Paragraph {
+    build_paragraph(
+        node,
+        area_size,
+        font_collection,
+        is_rendering,
+        default_font_family,
+        scale_factor,
+        |font_style, text_style| {
+            if let Some(background) = font_style.background {
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                paint.set_color(background);
+                text_style.set_background_paint(&paint);
+            }
+        },
+    )
+}
+
+/// Create a paragraph with only the outlines of the text spans that have a `text_stroke`,
+/// meant to be painted on top of the paragraph from [create_paragraph].
+/// Returns `None` if no text span has an outline.
+pub fn create_stroke_paragraph(
+    node: &DioxusNode,
+    area_size: &Size2D,
+    font_collection: &FontCollection,
+    is_rendering: bool,
+    default_font_family: &[String],
+    scale_factor: f32,
+) -> Option<Paragraph> {
+    let has_stroke = node.children().iter().any(|text_span| {
+        text_span
+            .get::<FontStyleState>()
+            .is_some_and(|font_style| font_style.text_stroke.is_some())
+    });
+
+    if !has_stroke {
+        return None;
+    }
+
+    Some(build_paragraph(
+        node,
+        area_size,
+        font_collection,
+        is_rendering,
+        default_font_family,
+        scale_factor,
+        |font_style, text_style| {
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+
+            if let Some(text_stroke) = font_style.text_stroke {
+                paint.set_style(PaintStyle::Stroke);
+                paint.set_stroke_width(text_stroke.width * scale_factor);
+                paint.set_color(text_stroke.color);
+            } else {
+                paint.set_color(Color::TRANSPARENT);
+            }
+
+            // Only the outlines are painted, the rest is already in the filled paragraph
+            text_style.set_foreground_paint(&paint);
+            text_style.reset_shadows();
+            text_style.set_decoration(&Decoration::default());
+        },
+    ))
+}
+
+fn build_paragraph(
+    node: &DioxusNode,
+    area_size: &Size2D,
+    font_collection: &FontCollection,
+    is_rendering: bool,
+    default_font_family: &[String],
+    scale_factor: f32,
+    span_style: impl Fn(&FontStyleState, &mut TextStyle),
 ) -> Paragraph {
     let font_style = &*node.get::<FontStyleState>().unwrap();
 
@@ -222,7 +375,8 @@ pub fn create_paragraph(
 
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
-    let text_style = font_style.text_style(default_font_family, scale_factor);
+    let mut text_style = font_style.text_style(default_font_family, scale_factor);
+    span_style(font_style, &mut text_style);
     paragraph_builder.push_style(&text_style);
 
     for text_span in node.children() {
@@ -231,8 +385,9 @@ pub fn create_paragraph(
                 let text_nodes = text_span.children();
                 let text_node = *text_nodes.first().unwrap();
                 let text_node_type = &*text_node.node_type();
-                let font_style = text_span.get::<FontStyleState>().unwrap();
-                let text_style = font_style.text_style(default_font_family, scale_factor);
+                let font_style = &*text_span.get::<FontStyleState>().unwrap();
+                let mut text_style = font_style.text_style(default_font_family, scale_factor);
+                span_style(font_style, &mut text_style);
                 paragraph_builder.push_style(&text_style);
 
                 if let NodeType::Text(text) = text_node_type {
@@ -1,39 +1,69 @@
 use freya_engine::prelude::*;
 use freya_native_core::real_dom::NodeImmutable;
 use freya_node_state::{
-    ReferencesState,
+    SamplingMode,
     StyleState,
 };
-use torin::geometry::Area;
+use torin::geometry::{
+    Area,
+    Size2D,
+};
 
-use crate::dom::DioxusNode;
+use super::ImagesCache;
+use crate::{
+    dom::DioxusNode,
+    skia::image_bytes,
+};
 
 /// Render an `image` element
-pub fn render_image(area: &Area, node_ref: &DioxusNode, canvas: &Canvas) {
+pub fn render_image(
+    area: &Area,
+    node_ref: &DioxusNode,
+    canvas: &Canvas,
+    scale_factor: f32,
+    images_cache: &mut ImagesCache,
+) {
     let node_style = node_ref.get::<StyleState>().unwrap();
-    let node_references = node_ref.get::<ReferencesState>().unwrap();
 
-    let draw_img = |bytes: &[u8]| {
-        let pic = Image::from_encoded(Data::new_copy(bytes));
-        if let Some(pic) = pic {
-            let mut paint = Paint::default();
-            paint.set_anti_alias(true);
-            canvas.draw_image_nine(
-                pic,
-                IRect::new(0, 0, 0, 0),
-                Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
-                FilterMode::Last,
-                Some(&paint),
-            );
-        }
+    let Some(image_data) = image_bytes(node_ref) else {
+        images_cache.remove(&node_ref.id());
+        return;
     };
 
-    if let Some(image_ref) = &node_references.image_ref {
-        let image_data = image_ref.0.lock().unwrap();
-        if let Some(image_data) = image_data.as_ref() {
-            draw_img(image_data)
-        }
-    } else if let Some(image_data) = &node_style.image_data {
-        draw_img(image_data.as_slice())
+    if let Some(pic) = images_cache.image(node_ref.id(), &image_data) {
+        let image_size = Size2D::new(pic.width() as f32, pic.height() as f32) * scale_factor;
+        let image_size = node_style.image_fit.fit(image_size, area.size);
+        let image_area = node_style.image_align.align(image_size, area);
+
+        let sampling = match node_style.sampling {
+            SamplingMode::Nearest => SamplingOptions::new(FilterMode::Nearest, MipmapMode::None),
+            SamplingMode::Linear => SamplingOptions::new(FilterMode::Linear, MipmapMode::None),
+            SamplingMode::Mipmap => SamplingOptions::new(FilterMode::Linear, MipmapMode::Linear),
+            SamplingMode::Cubic => SamplingOptions::from(CubicResampler::mitchell()),
+        };
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+
+        // The image might overflow its area, e.g `image_fit: "cover"`
+        canvas.save();
+        canvas.clip_rect(
+            Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+            ClipOp::Intersect,
+            true,
+        );
+        canvas.draw_image_rect_with_sampling_options(
+            pic,
+            None,
+            Rect::new(
+                image_area.min_x(),
+                image_area.min_y(),
+                image_area.max_x(),
+                image_area.max_y(),
+            ),
+            sampling,
+            &paint,
+        );
+        canvas.restore();
     }
 }
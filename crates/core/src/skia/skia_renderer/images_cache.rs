@@ -0,0 +1,74 @@
+use freya_engine::prelude::*;
+use freya_native_core::NodeId;
+use freya_node_state::AttributesBytes;
+use rustc_hash::FxHashMap;
+
+use crate::dom::DioxusDOM;
+
+/// Decoded results of some bytes, `None` if they couldn't be decoded.
+type CacheEntry<T> = (AttributesBytes, Option<T>);
+
+/// Keeps the decoded images and parsed SVGs of the Nodes across frames,
+/// so they are only decoded again when their bytes change.
+#[derive(Default)]
+pub struct ImagesCache {
+    images: FxHashMap<NodeId, CacheEntry<Image>>,
+    svgs: FxHashMap<NodeId, CacheEntry<svg::Dom>>,
+}
+
+impl ImagesCache {
+    /// Get the decoded image of the given Node.
+    pub fn image(&mut self, node_id: NodeId, bytes: &AttributesBytes) -> Option<&Image> {
+        get_or_decode(&mut self.images, node_id, bytes, |bytes| {
+            Image::from_encoded(Data::new_copy(bytes))
+        })
+        .map(|image| &*image)
+    }
+
+    /// Get the parsed SVG of the given Node.
+    pub fn svg(
+        &mut self,
+        node_id: NodeId,
+        bytes: &AttributesBytes,
+        font_manager: &FontMgr,
+    ) -> Option<&mut svg::Dom> {
+        get_or_decode(&mut self.svgs, node_id, bytes, |bytes| {
+            svg::Dom::from_bytes(bytes, font_manager).ok()
+        })
+    }
+
+    /// Remove whatever is cached for the given Node.
+    pub fn remove(&mut self, node_id: &NodeId) {
+        self.images.remove(node_id);
+        self.svgs.remove(node_id);
+    }
+
+    /// Remove the cached images of the Nodes that are no longer in the DOM.
+    pub fn clean(&mut self, rdom: &DioxusDOM) {
+        self.images.retain(|node_id, _| rdom.contains(*node_id));
+        self.svgs.retain(|node_id, _| rdom.contains(*node_id));
+    }
+}
+
+fn get_or_decode<'a, T>(
+    cache: &'a mut FxHashMap<NodeId, CacheEntry<T>>,
+    node_id: NodeId,
+    bytes: &AttributesBytes,
+    decode: impl FnOnce(&[u8]) -> Option<T>,
+) -> Option<&'a mut T> {
+    // Compare the address first so unchanged bytes don't need to be compared by content
+    let is_cached = cache.get(&node_id).is_some_and(|(cached_bytes, _)| {
+        std::ptr::eq(cached_bytes.as_slice(), bytes.as_slice()) || cached_bytes == bytes
+    });
+
+    if !is_cached {
+        cache.insert(node_id, (bytes.clone(), decode(bytes.as_slice())));
+    }
+
+    let (cached_bytes, decoded) = cache.get_mut(&node_id)?;
+
+    // Hold the latest bytes so their address can't be reused by different bytes
+    *cached_bytes = bytes.clone();
+
+    decoded.as_mut()
+}
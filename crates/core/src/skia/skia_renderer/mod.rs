@@ -1,9 +1,13 @@
 mod image;
+mod images_cache;
 mod label;
 mod paragraph;
 mod rect;
 mod renderer;
+mod snapshot;
 mod svg;
 mod wireframe;
 
+pub use images_cache::*;
 pub use renderer::*;
+pub use snapshot::*;
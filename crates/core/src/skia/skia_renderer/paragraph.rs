@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use freya_common::CachedParagraph;
+use freya_common::{
+    CachedParagraph,
+    CachedStrokeParagraph,
+};
 use freya_engine::prelude::*;
 use freya_native_core::{
     real_dom::NodeImmutable,
@@ -15,7 +18,10 @@ use crate::{
         align_highlights_and_cursor_paragraph,
         align_main_align_paragraph,
     },
-    skia::create_paragraph,
+    skia::{
+        create_paragraph,
+        create_stroke_paragraph,
+    },
 };
 
 /// Render a `paragraph` element
@@ -29,8 +35,9 @@ pub fn render_paragraph(
     scale_factor: f32,
 ) {
     let node_cursor_state = &*dioxus_node.get::<CursorState>().unwrap();
+    let is_editing = node_cursor_state.position.is_some();
 
-    let paint = |paragraph: &Paragraph| {
+    let paint = |paragraph: &Paragraph, stroke_paragraph: Option<&Paragraph>| {
         let x = area.min_x();
         let y = area.min_y() + align_main_align_paragraph(dioxus_node, area, paragraph);
 
@@ -41,9 +48,14 @@ pub fn render_paragraph(
         draw_cursor(area, paragraph, canvas, dioxus_node);
 
         paragraph.paint(canvas, (x, y));
+
+        // Draw the outlines of the text on top
+        if let Some(stroke_paragraph) = stroke_paragraph {
+            stroke_paragraph.paint(canvas, (x, y));
+        }
     };
 
-    if node_cursor_state.position.is_some() {
+    if is_editing {
         let paragraph = create_paragraph(
             dioxus_node,
             &area.size,
@@ -52,10 +64,22 @@ pub fn render_paragraph(
             default_fonts,
             scale_factor,
         );
-        paint(&paragraph);
+        let stroke_paragraph = create_stroke_paragraph(
+            dioxus_node,
+            &area.size,
+            font_collection,
+            true,
+            default_fonts,
+            scale_factor,
+        );
+        paint(&paragraph, stroke_paragraph.as_ref());
     } else {
-        let paragraph = &data.as_ref().unwrap().get::<CachedParagraph>().unwrap().0;
-        paint(paragraph);
+        let data = data.as_ref().unwrap();
+        let paragraph = &data.get::<CachedParagraph>().unwrap().0;
+        let stroke_paragraph = data
+            .get::<CachedStrokeParagraph>()
+            .map(|stroke_paragraph| &stroke_paragraph.0);
+        paint(paragraph, stroke_paragraph);
     };
 }
 
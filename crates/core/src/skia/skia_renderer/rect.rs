@@ -1,8 +1,11 @@
 use freya_engine::prelude::*;
 use freya_native_core::real_dom::NodeImmutable;
 use freya_node_state::{
+    Border,
     BorderAlignment,
     BorderStyle,
+    Borders,
+    CornerRadius,
     Fill,
     ReferencesState,
     ShadowPosition,
@@ -26,7 +29,6 @@ pub fn render_rect(
     let node_style = &*node_ref.get::<StyleState>().unwrap();
 
     let mut paint = Paint::default();
-    let mut path = Path::new();
     let area = area.to_f32();
 
     paint.set_anti_alias(true);
@@ -41,30 +43,19 @@ pub fn render_rect(
         Fill::LinearGradient(gradient) => {
             paint.set_shader(gradient.into_shader(area));
         }
+        Fill::RadialGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(area));
+        }
+        Fill::ConicGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(area));
+        }
     }
 
     let mut radius = node_style.corner_radius;
     radius.scale(scale_factor);
 
-    let rounded_rect = RRect::new_rect_radii(
-        Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
-        &[
-            (radius.top_left, radius.top_left).into(),
-            (radius.top_right, radius.top_right).into(),
-            (radius.bottom_right, radius.bottom_right).into(),
-            (radius.bottom_left, radius.bottom_left).into(),
-        ],
-    );
-
-    if radius.smoothing > 0.0 {
-        path.add_path(
-            &radius.smoothed_path(rounded_rect),
-            (area.min_x(), area.min_y()),
-            None,
-        );
-    } else {
-        path.add_rrect(rounded_rect, None);
-    }
+    let rounded_rect = radius.rounded_rect(&area);
+    let path = radius.rounded_path(rounded_rect);
 
     canvas.draw_path(&path, &paint);
 
@@ -73,7 +64,6 @@ pub fn render_rect(
         if shadow.fill != Fill::Color(Color::TRANSPARENT) {
             shadow.scale(scale_factor);
             let mut shadow_paint = paint.clone();
-            let mut shadow_path = Path::new();
 
             match &shadow.fill {
                 Fill::Color(color) => {
@@ -82,6 +72,12 @@ pub fn render_rect(
                 Fill::LinearGradient(gradient) => {
                     shadow_paint.set_shader(gradient.into_shader(area));
                 }
+                Fill::RadialGradient(gradient) => {
+                    shadow_paint.set_shader(gradient.into_shader(area));
+                }
+                Fill::ConicGradient(gradient) => {
+                    shadow_paint.set_shader(gradient.into_shader(area));
+                }
             }
 
             // Shadows can be either outset or inset
@@ -108,18 +104,7 @@ pub fn render_rect(
                 ));
             }
 
-            // Add either the RRect or smoothed path based on whether smoothing is used.
-            if radius.smoothing > 0.0 {
-                shadow_path.add_path(
-                    &node_style
-                        .corner_radius
-                        .smoothed_path(rounded_rect.with_outset(outset)),
-                    Point::new(area.min_x(), area.min_y()) - outset,
-                    None,
-                );
-            } else {
-                shadow_path.add_rrect(rounded_rect.with_outset(outset), None);
-            }
+            let mut shadow_path = radius.rounded_path(rounded_rect.with_outset(outset));
 
             // Offset our path by the shadow's x and y coordinates.
             shadow_path.offset((shadow.x, shadow.y));
@@ -140,50 +125,24 @@ pub fn render_rect(
     }
 
     // Borders
-    if node_style.border.width > 0.0 && node_style.border.style != BorderStyle::None {
-        let mut border_with = node_style.border.width;
-        border_with *= scale_factor;
-
-        // Create a new paint and path
-        let mut border_paint = paint.clone();
-        let mut border_path = Path::new();
-
-        // Setup paint params
-        border_paint.set_anti_alias(true);
-        border_paint.set_style(PaintStyle::Stroke);
-        match &node_style.border.fill {
-            Fill::Color(color) => {
-                border_paint.set_color(*color);
-            }
-            Fill::LinearGradient(gradient) => {
-                border_paint.set_shader(gradient.into_shader(area));
-            }
-        }
-        border_paint.set_stroke_width(border_with);
-
-        // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
-        // For Inner and Outer borders, we need to grow or shrink the stroke path by half the border width.
-        let outset = Point::new(border_with / 2.0, border_with / 2.0)
-            * match node_style.border.alignment {
-                BorderAlignment::Center => 0.0,
-                BorderAlignment::Inner => -1.0,
-                BorderAlignment::Outer => 1.0,
-            };
+    if node_style.border.is_visible() {
+        let mut border = node_style.border.clone();
+        border.scale(scale_factor);
 
-        // Add either the RRect or smoothed path based on whether smoothing is used.
-        if radius.smoothing > 0.0 {
-            border_path.add_path(
-                &node_style
-                    .corner_radius
-                    .smoothed_path(rounded_rect.with_outset(outset)),
-                Point::new(area.min_x(), area.min_y()) - outset,
-                None,
-            );
+        if border.is_uniform() {
+            render_border(canvas, &border.top, rounded_rect, &radius, area);
         } else {
-            border_path.add_rrect(rounded_rect.with_outset(outset), None);
+            // Every side strokes the whole outline, but only what is inside its own edge is kept
+            let clips = border_sides_clips(area, &border);
+            for (side, clip) in border.sides().into_iter().zip(clips) {
+                if side.is_visible() {
+                    canvas.save();
+                    canvas.clip_path(&clip, ClipOp::Intersect, true);
+                    render_border(canvas, side, rounded_rect, &radius, area);
+                    canvas.restore();
+                }
+            }
         }
-
-        canvas.draw_path(&border_path, &border_paint);
     }
 
     let references = node_ref.get::<ReferencesState>().unwrap();
@@ -192,3 +151,131 @@ pub fn render_rect(
         (canvas_ref.runner)(canvas, font_collection, area, scale_factor);
     }
 }
+
+/// Stroke the outline of a `rect` with the given border.
+fn render_border(
+    canvas: &Canvas,
+    border: &Border,
+    rounded_rect: RRect,
+    corner_radius: &CornerRadius,
+    area: Area,
+) {
+    // Create a new paint
+    let mut border_paint = Paint::default();
+
+    // Setup paint params
+    border_paint.set_anti_alias(true);
+    border_paint.set_style(PaintStyle::Stroke);
+    match &border.fill {
+        Fill::Color(color) => {
+            border_paint.set_color(*color);
+        }
+        Fill::LinearGradient(gradient) => {
+            border_paint.set_shader(gradient.into_shader(area));
+        }
+        Fill::RadialGradient(gradient) => {
+            border_paint.set_shader(gradient.into_shader(area));
+        }
+        Fill::ConicGradient(gradient) => {
+            border_paint.set_shader(gradient.into_shader(area));
+        }
+    }
+    border_paint.set_stroke_width(border.width);
+
+    match border.style {
+        BorderStyle::Dashed => {
+            border_paint.set_path_effect(PathEffect::dash(
+                &[border.width * 3.0, border.width * 2.0],
+                0.0,
+            ));
+        }
+        BorderStyle::Dotted => {
+            // Round caps turn the zero-length dashes into dots
+            border_paint.set_stroke_cap(PaintCap::Round);
+            border_paint.set_path_effect(PathEffect::dash(&[0.0, border.width * 2.0], 0.0));
+        }
+        BorderStyle::Solid | BorderStyle::None => {}
+    }
+
+    // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
+    // For Inner and Outer borders, we need to grow or shrink the stroke path by half the border width.
+    let outset = Point::new(border.width / 2.0, border.width / 2.0)
+        * match border.alignment {
+            BorderAlignment::Center => 0.0,
+            BorderAlignment::Inner => -1.0,
+            BorderAlignment::Outer => 1.0,
+        };
+
+    let border_path = corner_radius.rounded_path(rounded_rect.with_outset(outset));
+
+    canvas.draw_path(&border_path, &border_paint);
+}
+
+/// Create the clip of each border side, in the order top, right, bottom and left.
+/// Sides are split by the lines that go from the corners of the inner area to the corners of the element,
+/// so the width of the adjacent sides decides where they meet.
+fn border_sides_clips(area: Area, border: &Borders) -> [Path; 4] {
+    // How far outside of the element the clips reach, enough to cover outer borders
+    let reach = border
+        .sides()
+        .iter()
+        .fold(0.0f32, |reach, side| reach.max(side.width))
+        + 1.0;
+
+    let corners = [
+        (area.min_x(), area.min_y()),
+        (area.max_x(), area.min_y()),
+        (area.max_x(), area.max_y()),
+        (area.min_x(), area.max_y()),
+    ];
+    let inner_corners = [
+        (
+            area.min_x() + border.left.width,
+            area.min_y() + border.top.width,
+        ),
+        (
+            area.max_x() - border.right.width,
+            area.min_y() + border.top.width,
+        ),
+        (
+            area.max_x() - border.right.width,
+            area.max_y() - border.bottom.width,
+        ),
+        (
+            area.min_x() + border.left.width,
+            area.max_y() - border.bottom.width,
+        ),
+    ];
+
+    let outer_corners = [0, 1, 2, 3].map(|i| {
+        let (x, y) = corners[i];
+        let (inner_x, inner_y) = inner_corners[i];
+        let (mut dx, mut dy) = (x - inner_x, y - inner_y);
+
+        // Sides without width meet diagonally
+        if dx == 0.0 && dy == 0.0 {
+            dx = if i == 0 || i == 3 { -1.0 } else { 1.0 };
+            dy = if i < 2 { -1.0 } else { 1.0 };
+        }
+
+        let length = match (dx == 0.0, dy == 0.0) {
+            (true, _) => dy.abs(),
+            (_, true) => dx.abs(),
+            _ => dx.abs().min(dy.abs()),
+        };
+        let factor = reach / length;
+
+        (x + dx * factor, y + dy * factor)
+    });
+
+    [0, 1, 2, 3].map(|i| {
+        let next = (i + 1) % 4;
+        let mut path = Path::new();
+        path.move_to(outer_corners[i]);
+        path.line_to(outer_corners[next]);
+        path.line_to(inner_corners[next]);
+        path.line_to(inner_corners[i]);
+        path.close();
+        path
+    })
+}
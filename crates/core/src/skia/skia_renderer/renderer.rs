@@ -7,6 +7,7 @@ use freya_native_core::{
     NodeId,
 };
 use freya_node_state::{
+    CornerRadius,
     StyleState,
     TransformState,
     ViewportState,
@@ -17,6 +18,7 @@ use torin::{
         LayoutNode,
         Torin,
     },
+    scaled::Scaled,
 };
 
 use super::{
@@ -26,61 +28,74 @@ use super::{
     rect::render_rect,
     svg::render_svg,
     wireframe,
+    ImagesCache,
 };
 use crate::dom::DioxusNode;
 
-fn clip_viewport(canvas: &Canvas, viewport: &Area) {
-    canvas.clip_rect(
-        Rect::new(
-            viewport.min_x(),
-            viewport.min_y(),
-            viewport.max_x(),
-            viewport.max_y(),
-        ),
-        ClipOp::Intersect,
-        true,
-    );
+fn clip_viewport(canvas: &Canvas, viewport: &Area, corner_radius: &CornerRadius) {
+    if *corner_radius == CornerRadius::default() {
+        canvas.clip_rect(
+            Rect::new(
+                viewport.min_x(),
+                viewport.min_y(),
+                viewport.max_x(),
+                viewport.max_y(),
+            ),
+            ClipOp::Intersect,
+            true,
+        );
+    } else {
+        // Clip with the same shape the viewport element is drawn with
+        let path = corner_radius.rounded_path(corner_radius.rounded_rect(viewport));
+        canvas.clip_path(&path, ClipOp::Intersect, true);
+    }
+}
+
+/// Bounds of a blurred area, with room for its blurred edges.
+fn blur_bounds(area: &Area, sigma: f32) -> Rect {
+    let extent = sigma * 3.0;
+    Rect::new(
+        area.min_x() - extent,
+        area.min_y() - extent,
+        area.max_x() + extent,
+        area.max_y() + extent,
+    )
 }
 
 pub struct SkiaRenderer<'a> {
     pub canvas: &'a Canvas,
     pub font_collection: &'a mut FontCollection,
     pub font_manager: &'a FontMgr,
+    pub images_cache: &'a mut ImagesCache,
     pub matrices: Vec<(Matrix, Vec<NodeId>)>,
     pub opacities: Vec<(f32, Vec<NodeId>)>,
+    pub blurs: Vec<(f32, Vec<NodeId>)>,
     pub default_fonts: &'a [String],
     pub scale_factor: f32,
 }
 
 impl SkiaRenderer<'_> {
-    /// Render a node into the Skia canvas
+    /// Render a node into the Skia canvas.
+    /// Nodes that are not damaged are not painted, they only pass their effects to their children.
     pub fn render(
         &mut self,
         layout_node: &LayoutNode,
         dioxus_node: &DioxusNode,
         render_wireframe: bool,
         layout: &Torin<NodeId>,
+        is_damaged: bool,
     ) {
         let area = layout_node.visible_area();
         let data = &layout_node.data;
         let node_type = &*dioxus_node.node_type();
         if let NodeType::Element(ElementNode { tag, .. }) = node_type {
-            self.canvas.save();
+            let initial_layer = self.canvas.save();
 
             let node_transform = &*dioxus_node.get::<TransformState>().unwrap();
             let node_style = &*dioxus_node.get::<StyleState>().unwrap();
 
-            // Pass rotate effect to children
-            if let Some(rotate_degs) = node_transform.rotate_degs {
-                let mut matrix = Matrix::new_identity();
-                matrix.set_rotate(
-                    rotate_degs,
-                    Some(Point {
-                        x: area.min_x() + area.width() / 2.0,
-                        y: area.min_y() + area.height() / 2.0,
-                    }),
-                );
-
+            // Pass transform effects to children
+            if let Some(matrix) = node_transform.matrix(&area, self.scale_factor) {
                 self.matrices.push((matrix, vec![dioxus_node.id()]));
             }
 
@@ -89,6 +104,28 @@ impl SkiaRenderer<'_> {
                 self.opacities.push((opacity, vec![dioxus_node.id()]));
             }
 
+            // Pass blur effect to children
+            if let Some(blur) = node_style.blur {
+                self.blurs.push((blur, vec![dioxus_node.id()]));
+            }
+
+            if !is_damaged {
+                let inherited_nodes = self
+                    .matrices
+                    .iter_mut()
+                    .map(|(_, nodes)| nodes)
+                    .chain(self.opacities.iter_mut().map(|(_, nodes)| nodes))
+                    .chain(self.blurs.iter_mut().map(|(_, nodes)| nodes));
+                for nodes in inherited_nodes {
+                    if nodes.contains(&dioxus_node.id()) {
+                        nodes.extend(dioxus_node.child_ids());
+                    }
+                }
+
+                self.canvas.restore_to_count(initial_layer);
+                return;
+            }
+
             // Apply inherited matrices
             for (matrix, nodes) in self.matrices.iter_mut() {
                 if nodes.contains(&dioxus_node.id()) {
@@ -110,18 +147,69 @@ impl SkiaRenderer<'_> {
                 }
             }
 
+            // Apply inherited blur effects
+            for (blur, nodes) in self.blurs.iter_mut() {
+                if nodes.contains(&dioxus_node.id()) {
+                    let sigma = *blur * self.scale_factor;
+                    let bounds = blur_bounds(&area, sigma);
+
+                    let mut paint = Paint::default();
+                    paint.set_image_filter(image_filters::blur(
+                        (sigma, sigma),
+                        TileMode::Decal,
+                        None,
+                        &bounds,
+                    ));
+                    self.canvas
+                        .save_layer(&SaveLayerRec::default().bounds(&bounds).paint(&paint));
+
+                    nodes.extend(dioxus_node.child_ids());
+                }
+            }
+
             // Clip all elements with their corresponding viewports
             let node_viewports = dioxus_node.get::<ViewportState>().unwrap();
             // Only clip the element iself when it's paragraph because
             // it will render the inner text spans on it's own, so if these spans overflow the paragraph,
             // It is the paragraph job to make sure they are clipped
             if !node_viewports.viewports.is_empty() && *tag == TagName::Paragraph {
-                clip_viewport(self.canvas, &layout_node.visible_area());
+                clip_viewport(
+                    self.canvas,
+                    &layout_node.visible_area(),
+                    &CornerRadius::default(),
+                );
             }
 
             for viewport_id in &node_viewports.viewports {
                 let viewport = layout.get(*viewport_id).unwrap().visible_area();
-                clip_viewport(self.canvas, &viewport);
+                let viewport_node = dioxus_node.real_dom().get(*viewport_id).unwrap();
+                let mut corner_radius = viewport_node.get::<StyleState>().unwrap().corner_radius;
+                corner_radius.scale(self.scale_factor);
+                clip_viewport(self.canvas, &viewport, &corner_radius);
+            }
+
+            // Blur what is already painted behind the element
+            if let Some(backdrop_blur) = node_style.backdrop_blur {
+                let sigma = backdrop_blur * self.scale_factor;
+                let bounds = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+
+                if let Some(blur_filter) =
+                    image_filters::blur((sigma, sigma), TileMode::Clamp, None, &bounds)
+                {
+                    let mut corner_radius = node_style.corner_radius;
+                    corner_radius.scale(self.scale_factor);
+                    let path = corner_radius.rounded_path(corner_radius.rounded_rect(&area));
+
+                    self.canvas.save();
+                    self.canvas.clip_path(&path, ClipOp::Intersect, true);
+                    self.canvas.save_layer(
+                        &SaveLayerRec::default()
+                            .bounds(&bounds)
+                            .backdrop(&blur_filter),
+                    );
+                    self.canvas.restore();
+                    self.canvas.restore();
+                }
             }
 
             match tag {
@@ -149,10 +237,22 @@ impl SkiaRenderer<'_> {
                     );
                 }
                 TagName::Svg => {
-                    render_svg(&area, dioxus_node, self.canvas, self.font_manager);
+                    render_svg(
+                        &area,
+                        dioxus_node,
+                        self.canvas,
+                        self.font_manager,
+                        self.images_cache,
+                    );
                 }
                 TagName::Image => {
-                    render_image(&area, dioxus_node, self.canvas);
+                    render_image(
+                        &area,
+                        dioxus_node,
+                        self.canvas,
+                        self.scale_factor,
+                        self.images_cache,
+                    );
                 }
                 _ => {}
             }
@@ -161,7 +261,7 @@ impl SkiaRenderer<'_> {
                 wireframe::render_wireframe(self.canvas, &area);
             }
 
-            self.canvas.restore();
+            self.canvas.restore_to_count(initial_layer);
         }
     }
 }
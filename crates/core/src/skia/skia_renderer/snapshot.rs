@@ -0,0 +1,68 @@
+use freya_common::SnapshotConfig;
+use freya_engine::prelude::*;
+use freya_native_core::NodeId;
+
+use super::{
+    ImagesCache,
+    SkiaRenderer,
+};
+use crate::{
+    dom::FreyaDOM,
+    render::traverse_visible_subtree,
+};
+
+/// Render the given Node and its descendants into an encoded image.
+/// The effects inherited from its ancestors, such as opacity or rotations, are not applied.
+#[allow(clippy::too_many_arguments)]
+pub fn render_node_snapshot(
+    fdom: &FreyaDOM,
+    node_id: NodeId,
+    config: &SnapshotConfig,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    images_cache: &mut ImagesCache,
+    default_fonts: &[String],
+    scale_factor: f32,
+) -> Option<Vec<u8>> {
+    let layout = fdom.layout();
+    let area = layout.get(node_id)?.visible_area();
+
+    let width = (area.width() * config.scale).ceil() as i32;
+    let height = (area.height() * config.scale).ceil() as i32;
+    if width <= 0 || height <= 0 {
+        return None;
+    }
+
+    let mut surface = raster_n32_premul((width, height))?;
+
+    {
+        let canvas = surface.canvas();
+        canvas.clear(Color::TRANSPARENT);
+        canvas.scale((config.scale, config.scale));
+        canvas.translate((-area.min_x(), -area.min_y()));
+
+        let mut skia_renderer = SkiaRenderer {
+            canvas,
+            font_collection,
+            font_manager,
+            images_cache,
+            matrices: Vec::default(),
+            opacities: Vec::default(),
+            blurs: Vec::default(),
+            default_fonts,
+            scale_factor,
+        };
+
+        traverse_visible_subtree(fdom, &layout, node_id, |node_id, layout_node| {
+            if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
+                skia_renderer.render(layout_node, &dioxus_node, false, &layout, true);
+            }
+        });
+    }
+
+    let image = surface.image_snapshot();
+    let mut context = surface.direct_context();
+    let data = image.encode(context.as_mut(), config.format, config.quality)?;
+
+    Some(data.as_bytes().to_vec())
+}
@@ -3,22 +3,31 @@ use freya_native_core::real_dom::NodeImmutable;
 use freya_node_state::StyleState;
 use torin::geometry::Area;
 
+use super::ImagesCache;
 use crate::dom::DioxusNode;
 
 /// Render a `svg` element
-pub fn render_svg(area: &Area, node_ref: &DioxusNode, canvas: &Canvas, font_manager: &FontMgr) {
+pub fn render_svg(
+    area: &Area,
+    node_ref: &DioxusNode,
+    canvas: &Canvas,
+    font_manager: &FontMgr,
+    images_cache: &mut ImagesCache,
+) {
     let node_style = &*node_ref.get::<StyleState>().unwrap();
 
     let x = area.min_x();
     let y = area.min_y();
     if let Some(svg_data) = &node_style.svg_data {
-        let svg_dom = svg::Dom::from_bytes(svg_data.as_slice(), font_manager);
-        if let Ok(mut svg_dom) = svg_dom {
+        let svg_dom = images_cache.svg(node_ref.id(), svg_data, font_manager);
+        if let Some(svg_dom) = svg_dom {
             canvas.save();
             canvas.translate((x, y));
             svg_dom.set_container_size((area.width() as i32, area.height() as i32));
             svg_dom.render(canvas);
             canvas.restore();
         }
+    } else {
+        images_cache.remove(&node_ref.id());
     }
 }
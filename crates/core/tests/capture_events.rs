@@ -0,0 +1,138 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn capture_before_bubbling() {
+    fn app() -> Element {
+        let mut state = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclickcapture: move |_| state.push("outer capture".to_string()),
+                onclick: move |_| state.push("outer click".to_string()),
+                rect {
+                    height: "100",
+                    width: "100",
+                    background: "red",
+                    onclickcapture: move |_| state.push("inner capture".to_string()),
+                    onclick: move |_| state.push("inner click".to_string()),
+                }
+                label {
+                    "{state:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec![
+                    "outer capture",
+                    "inner capture",
+                    "inner click",
+                    "outer click"
+                ]
+            )
+            .as_str()
+        )
+    );
+
+    // Only the ancestors of the clicked node capture the event
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(250.0, 250.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec![
+                    "outer capture",
+                    "inner capture",
+                    "inner click",
+                    "outer click",
+                    "outer capture",
+                    "outer click"
+                ]
+            )
+            .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+pub async fn prevent_default_from_capture() {
+    fn app() -> Element {
+        let mut state = use_signal(String::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onkeydowncapture: move |e: KeyboardEvent| {
+                    if e.key == Key::Tab {
+                        e.prevent_default();
+                    }
+                },
+                rect {
+                    onkeydown: move |e: KeyboardEvent| {
+                        state.set(format!("{:?} {}", e.key, e.default_prevented()));
+                    },
+                    label {
+                        "{state}"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(0).get(0);
+
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Tab true"));
+
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Enter,
+        code: Code::Enter,
+        modifiers: Modifiers::default(),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Enter false"));
+}
@@ -0,0 +1,84 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn focus_events() {
+    #[component]
+    fn Focusable(name: &'static str, state: Signal<Vec<String>>) -> Element {
+        let mut focus = use_focus();
+
+        rsx!(rect {
+            width: "100%",
+            height: "50%",
+            focus_id: focus.attribute(),
+            onclick: move |_| focus.focus(),
+            onfocus: move |_| state.push(format!("focus {name}")),
+            onblur: move |_| state.push(format!("blur {name}")),
+        })
+    }
+
+    fn app() -> Element {
+        let mut state = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onfocusin: move |_| state.push("focusin".to_string()),
+                onfocusout: move |_| state.push("focusout".to_string()),
+                Focusable { name: "a", state }
+                Focusable { name: "b", state }
+            }
+            label {
+                "{state:?}"
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+
+    let label = utils.root().get(1);
+
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[]"));
+
+    // Click on the first rect
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["focus a", "focusin"]).as_str())
+    );
+
+    // Click on the second rect
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 75.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec!["focus a", "focusin", "blur a", "focusout", "focus b", "focusin"]
+            )
+            .as_str()
+        )
+    );
+}
@@ -0,0 +1,168 @@
+use std::sync::{
+    Arc,
+    Mutex,
+    OnceLock,
+};
+
+use bytes::Bytes;
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+static RUST_LOGO: &[u8] = include_bytes!("../../../examples/rust_logo.png");
+static FREYA_ICON: &[u8] = include_bytes!("../../../examples/freya_icon.png");
+
+#[tokio::test]
+pub async fn auto_sized_image_new_bytes() {
+    fn app() -> Element {
+        let mut big = use_signal(|| false);
+        let image_data = static_bytes(if big() { RUST_LOGO } else { FREYA_ICON });
+
+        rsx!(image {
+            image_data,
+            onclick: move |_| big.set(true)
+        })
+    }
+
+    let mut utils = launch_test(app);
+
+    let image = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(image.area().unwrap().size, Size2D::new(32., 32.));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(5.0, 5.0),
+        button: Some(MouseButton::Left),
+    });
+
+    let (_, must_relayout) = utils.wait_for_update().await;
+
+    assert!(must_relayout);
+    assert_eq!(image.area().unwrap().size, Size2D::new(1024., 1024.));
+}
+
+#[tokio::test]
+pub async fn auto_sized_image_filled_reference() {
+    static IMAGE_REFERENCE: OnceLock<Arc<Mutex<Option<Bytes>>>> = OnceLock::new();
+
+    fn app() -> Element {
+        let image_reference = AttributeValue::any_value(CustomAttributeValues::ImageReference(
+            ImageReference(IMAGE_REFERENCE.get_or_init(Arc::default).clone()),
+        ));
+
+        rsx!(image { image_reference })
+    }
+
+    let mut utils = launch_test(app);
+
+    let image = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(image.area().unwrap().size, Size2D::new(0., 0.));
+    assert!(!utils.sdom().get().invalidate_image_references());
+
+    IMAGE_REFERENCE
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .replace(Bytes::from_static(FREYA_ICON));
+
+    assert!(utils.sdom().get().invalidate_image_references());
+
+    utils.wait_for_update().await;
+
+    assert_eq!(image.area().unwrap().size, Size2D::new(32., 32.));
+    assert!(!utils.sdom().get().invalidate_image_references());
+}
+
+#[tokio::test]
+pub async fn auto_sized_image_same_size_reference() {
+    static IMAGE_REFERENCE: OnceLock<Arc<Mutex<Option<Bytes>>>> = OnceLock::new();
+
+    fn app() -> Element {
+        let image_reference =
+            AttributeValue::any_value(CustomAttributeValues::ImageReference(ImageReference(
+                IMAGE_REFERENCE
+                    .get_or_init(|| Arc::new(Mutex::new(Some(Bytes::from_static(FREYA_ICON)))))
+                    .clone(),
+            )));
+
+        rsx!(image { image_reference })
+    }
+
+    let mut utils = launch_test(app);
+
+    let image = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(image.area().unwrap().size, Size2D::new(32., 32.));
+
+    // A new frame with the same size doesn't need a new layout
+    IMAGE_REFERENCE
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .replace(Bytes::copy_from_slice(FREYA_ICON));
+
+    assert!(!utils.sdom().get().invalidate_image_references());
+    assert!(!utils.sdom().get().invalidate_image_references());
+
+    // Until the size changes
+    IMAGE_REFERENCE
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .replace(Bytes::from_static(RUST_LOGO));
+
+    assert!(utils.sdom().get().invalidate_image_references());
+
+    utils.wait_for_update().await;
+
+    assert_eq!(image.area().unwrap().size, Size2D::new(1024., 1024.));
+}
+
+#[tokio::test]
+pub async fn image_reference_new_bytes_damage() {
+    static IMAGE_REFERENCE: OnceLock<Arc<Mutex<Option<Bytes>>>> = OnceLock::new();
+
+    fn app() -> Element {
+        let image_reference = AttributeValue::any_value(CustomAttributeValues::ImageReference(
+            ImageReference(IMAGE_REFERENCE.get_or_init(Arc::default).clone()),
+        ));
+
+        rsx!(image {
+            width: "100",
+            height: "100",
+            image_reference
+        })
+    }
+
+    let mut utils = launch_test(app);
+
+    utils.wait_for_update().await;
+
+    assert_eq!(process_damage(&utils.sdom().get(), 1.0), Damage::Full);
+    assert_eq!(process_damage(&utils.sdom().get(), 1.0), Damage::None);
+
+    IMAGE_REFERENCE
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .replace(Bytes::from_static(FREYA_ICON));
+
+    // What requesting a new animation frame does
+    utils.sdom().get().damage_referenced_elements();
+
+    assert_eq!(
+        process_damage(&utils.sdom().get(), 1.0),
+        Damage::Partial(vec![Area::new(
+            Point2D::new(-1.0, -1.0),
+            Size2D::new(102.0, 102.0)
+        )])
+    );
+}
@@ -0,0 +1,58 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn mouse_up_and_double_click() {
+    fn app() -> Element {
+        let mut state = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onmousedown: move |e: MouseEvent| state.push(format!("down {}", e.get_click_count())),
+                onmouseup: move |e: MouseEvent| state.push(format!("up {}", e.get_click_count())),
+                onclick: move |e: MouseEvent| state.push(format!("click {}", e.get_click_count())),
+                ondoubleclick: move |e: MouseEvent| state.push(format!("double {}", e.get_click_count())),
+                label {
+                    "{state:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(0);
+
+    for (name, click_count) in [
+        (EventName::MouseDown, 1),
+        (EventName::MouseUp, 1),
+        (EventName::Click, 1),
+        (EventName::MouseDown, 2),
+        (EventName::MouseUp, 2),
+        (EventName::Click, 2),
+        (EventName::DoubleClick, 2),
+    ] {
+        utils.push_event(PlatformEvent::MouseClick {
+            name,
+            cursor: CursorPoint::new(50.0, 50.0),
+            button: MouseButton::Left,
+            click_count,
+        });
+    }
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec!["down 1", "up 1", "click 1", "down 2", "up 2", "click 2", "double 2"]
+            )
+            .as_str()
+        )
+    );
+}
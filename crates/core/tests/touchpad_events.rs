@@ -0,0 +1,137 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn touchpad_gestures() {
+    fn app() -> Element {
+        let mut state = use_signal(Vec::<String>::new);
+
+        let ongesture = move |e: TouchpadEvent| {
+            state.push(format!("{:?} {:?}", e.get_gesture(), e.get_phase()));
+        };
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onpinch: ongesture,
+                onrotate: ongesture,
+                onpan: ongesture,
+                label {
+                    "{state:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(0);
+
+    for (name, gesture, phase) in [
+        (
+            EventName::Pinch,
+            TouchpadGesture::Pinch { delta: 0.5 },
+            TouchPhase::Started,
+        ),
+        (
+            EventName::Rotate,
+            TouchpadGesture::Rotate { delta: 90.0 },
+            TouchPhase::Moved,
+        ),
+        (
+            EventName::Pan,
+            TouchpadGesture::Pan {
+                delta_x: 1.0,
+                delta_y: 2.0,
+            },
+            TouchPhase::Ended,
+        ),
+    ] {
+        utils.push_event(PlatformEvent::Touchpad {
+            name,
+            cursor: CursorPoint::new(50.0, 50.0),
+            gesture,
+            phase,
+        });
+    }
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec![
+                    "Pinch { delta: 0.5 } Started",
+                    "Rotate { delta: 90.0 } Moved",
+                    "Pan { delta_x: 1.0, delta_y: 2.0 } Ended"
+                ]
+            )
+            .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+pub async fn wheel_delta_mode_and_phase() {
+    fn app() -> Element {
+        let mut state = use_signal(String::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onwheel: move |e: WheelEvent| {
+                    state.set(format!("{:?} {:?}", e.get_delta_mode(), e.get_phase()));
+                },
+                label {
+                    "{state}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(0);
+
+    utils.push_event(PlatformEvent::Wheel {
+        name: EventName::Wheel,
+        scroll: (0., 0.).into(),
+        cursor: (50., 50.).into(),
+        delta_mode: WheelDeltaMode::Pixel,
+        phase: TouchPhase::Started,
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Pixel Started"));
+
+    utils.push_event(PlatformEvent::Wheel {
+        name: EventName::Wheel,
+        scroll: (0., 0.).into(),
+        cursor: (50., 50.).into(),
+        delta_mode: WheelDeltaMode::Pixel,
+        phase: TouchPhase::Ended,
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Pixel Ended"));
+
+    utils.push_event(PlatformEvent::Wheel {
+        name: EventName::Wheel,
+        scroll: (0., 53.).into(),
+        cursor: (50., 50.).into(),
+        delta_mode: WheelDeltaMode::Line,
+        phase: TouchPhase::Moved,
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Line Moved"));
+}
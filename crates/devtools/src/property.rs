@@ -2,7 +2,7 @@ use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
 use freya_engine::prelude::*;
 use freya_node_state::{
-    Border,
+    Borders,
     Fill,
     Shadow,
 };
@@ -41,7 +41,7 @@ pub fn Property(name: String, value: String) -> Element {
 
 #[allow(non_snake_case)]
 #[component]
-pub fn LinearGradientProperty(name: String, fill: Fill) -> Element {
+pub fn GradientProperty(name: String, fill: Fill) -> Element {
     rsx!(
         rect {
             padding: "5 10",
@@ -173,7 +173,14 @@ pub fn ShadowProperty(name: String, shadow: Shadow) -> Element {
 
 #[allow(non_snake_case)]
 #[component]
-pub fn BorderProperty(name: String, border: Border) -> Element {
+pub fn BorderProperty(name: String, border: Borders) -> Element {
+    let widths = if border.is_uniform() {
+        border.top.width.to_string()
+    } else {
+        border.sides().map(|side| side.width.to_string()).join(" ")
+    };
+    let border = border.top;
+
     rsx!(
         rect {
             overflow: "clip",
@@ -195,7 +202,7 @@ pub fn BorderProperty(name: String, border: Border) -> Element {
                 text {
                     font_size: "15",
                     color: "rgb(252,181,172)",
-                    "{border.width} {border.style:?} {border.alignment:?}"
+                    "{widths} {border.style:?} {border.alignment:?}"
                 }
             }
             rect {
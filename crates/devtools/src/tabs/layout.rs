@@ -9,6 +9,7 @@ use freya_native_core::NodeId;
 
 use crate::{
     hooks::use_node_info,
+    property::Property,
     NodeIdSerializer,
 };
 
@@ -118,6 +119,18 @@ pub fn NodeInspectorLayout(node_id: String) -> Element {
                     }
                 }
             }
+            Property {
+                name: "direction",
+                value: node.state.size.direction.pretty()
+            }
+            Property {
+                name: "main_align",
+                value: node.state.size.main_alignment.pretty()
+            }
+            Property {
+                name: "cross_align",
+                value: node.state.size.cross_alignment.pretty()
+            }
         }
     )
 }
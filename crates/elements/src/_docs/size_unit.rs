@@ -46,13 +46,26 @@
 //! ##### `calc()`
 //!
 //! For more complex logic you can use the `calc()` function.
+//! It supports the `+`, `-`, `*` and `/` operators with their usual precedence, parentheses,
+//! logical pixels, parent percentages (`%`), viewport percentages (`v`) and the `min()`, `max()` and `clamp(minimum, value, maximum)` functions.
+//! Invalid expressions are rejected and the attribute is ignored.
 //!
 //! ```rust, no_run
 //! # use freya::prelude::*;
 //! fn app() -> Element {
 //!     rsx!(rect {
 //!         width: "calc(33% - 60 + 15%)", // (1/3 of the parent minus 60) plus 15% of parent
-//!         height: "calc(100% - 10)"      // 100% of the parent minus 10
+//!         height: "calc((100% - 10) / 2)"  // Half of 100% of the parent minus 10
+//!     })
+//! }
+//! ```
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     rsx!(rect {
+//!         width: "calc(clamp(200, 50%, 600))", // Half of the parent but never smaller than 200 or bigger than 600
+//!         height: "calc(max(25v, 100) + 10)"   // 25% of the window height, at least 100, plus 10
 //!     })
 //! }
 //! ```
@@ -84,6 +97,36 @@
 //! Will have the same size of the biggest sibling element inside a container who has `content: fit`.
 //! For an example, see `content`.
 //!
+//! #### flex
+//! Share the remaining space of the main axis with the sibling elements using `flex()` sizes, proportionally to their weights,
+//! inside a container who has `content: flex`. In the cross axis, or in other containers, it behaves like `fill`.
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     rsx!(
+//!         rect {
+//!             content: "flex",
+//!             direction: "horizontal",
+//!             width: "400",
+//!             height: "100%",
+//!             rect {
+//!                 width: "100",
+//!                 height: "100%",
+//!             }
+//!             rect {
+//!                 width: "flex(2)", // Will have a width of 200px
+//!                 height: "100%",
+//!             }
+//!             rect {
+//!                 width: "flex(1)", // Will have a width of 100px
+//!                 height: "100%",
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
 //! #### Viewport percentage
 //! Relative percentage to the viewport (Window) equivalent value.
 //!
@@ -183,13 +183,26 @@ builder_constructors! {
         #[doc = include_str!("_docs/attributes/max_width_max_height.md")]
         max_height: String,
         max_width: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
         #[doc = include_str!("_docs/attributes/background.md")]
         background: String,
         #[doc = include_str!("_docs/attributes/border.md")]
         border: String,
+        border_top: String,
+        border_right: String,
+        border_bottom: String,
+        border_left: String,
         border_align: String,
         #[doc = include_str!("_docs/attributes/direction.md")]
         direction: String,
+        #[doc = include_str!("_docs/attributes/wrap.md")]
+        wrap: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_columns: String,
+        grid_rows: String,
+        grid_column: String,
+        grid_row: String,
         #[doc = include_str!("_docs/attributes/shadow.md")]
         shadow: String,
         #[doc = include_str!("_docs/attributes/corner.md")]
@@ -212,12 +225,26 @@ builder_constructors! {
         cross_align: String,
         #[doc = include_str!("_docs/attributes/text_align.md")]
         text_align: String,
+        #[doc = include_str!("_docs/attributes/text_shadow.md")]
+        text_shadow: String,
+        #[doc = include_str!("_docs/attributes/text_stroke.md")]
+        text_stroke: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
+        #[doc = include_str!("_docs/attributes/scale.md")]
+        scale: String,
+        #[doc = include_str!("_docs/attributes/translate.md")]
+        translate: String,
+        #[doc = include_str!("_docs/attributes/skew.md")]
+        skew: String,
+        #[doc = include_str!("_docs/attributes/transform_origin.md")]
+        transform_origin: String,
         #[doc = include_str!("_docs/attributes/overflow.md")]
         overflow: String,
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
+        #[doc = include_str!("_docs/attributes/spacing.md")]
+        spacing: String,
         #[doc = include_str!("_docs/attributes/position.md")]
         position: String,
         position_top: String,
@@ -226,8 +253,14 @@ builder_constructors! {
         position_left: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/blur.md")]
+        blur: String,
+        #[doc = include_str!("_docs/attributes/backdrop_blur.md")]
+        backdrop_blur: String,
         #[doc = include_str!("_docs/attributes/content.md")]
         content: String,
+        #[doc = include_str!("_docs/attributes/flex_shrink.md")]
+        flex_shrink: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
         line_height: String,
 
@@ -283,6 +316,14 @@ builder_constructors! {
         max_lines: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
+        #[doc = include_str!("_docs/attributes/scale.md")]
+        scale: String,
+        #[doc = include_str!("_docs/attributes/translate.md")]
+        translate: String,
+        #[doc = include_str!("_docs/attributes/skew.md")]
+        skew: String,
+        #[doc = include_str!("_docs/attributes/transform_origin.md")]
+        transform_origin: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
         line_height: String,
         #[doc = include_str!("_docs/attributes/letter_spacing.md")]
@@ -300,6 +341,11 @@ builder_constructors! {
         focusable: String,
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/flex_shrink.md")]
+        flex_shrink: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
 
@@ -346,6 +392,14 @@ builder_constructors! {
         text_align: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
+        #[doc = include_str!("_docs/attributes/scale.md")]
+        scale: String,
+        #[doc = include_str!("_docs/attributes/translate.md")]
+        translate: String,
+        #[doc = include_str!("_docs/attributes/skew.md")]
+        skew: String,
+        #[doc = include_str!("_docs/attributes/transform_origin.md")]
+        transform_origin: String,
         #[doc = include_str!("_docs/attributes/font_size.md")]
         font_size: String,
         #[doc = include_str!("_docs/attributes/font_family.md")]
@@ -373,6 +427,11 @@ builder_constructors! {
         focusable: String,
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/flex_shrink.md")]
+        flex_shrink: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
 
@@ -394,8 +453,12 @@ builder_constructors! {
     text {
         #[doc = include_str!("_docs/attributes/color.md")]
         color: String,
-        #[doc = include_str!("_docs/attributes/font_size.md")]
+        #[doc = include_str!("_docs/attributes/span_background.md")]
+        background: String,
+        #[doc = include_str!("_docs/attributes/text_shadow.md")]
         text_shadow: String,
+        #[doc = include_str!("_docs/attributes/text_stroke.md")]
+        text_stroke: String,
         #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
@@ -445,10 +508,28 @@ builder_constructors! {
        #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
+        #[doc = include_str!("_docs/attributes/flex_shrink.md")]
+        flex_shrink: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
+        #[doc = include_str!("_docs/attributes/scale.md")]
+        scale: String,
+        #[doc = include_str!("_docs/attributes/translate.md")]
+        translate: String,
+        #[doc = include_str!("_docs/attributes/skew.md")]
+        skew: String,
+        #[doc = include_str!("_docs/attributes/transform_origin.md")]
+        transform_origin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/image_fit.md")]
+        image_fit: String,
+        #[doc = include_str!("_docs/attributes/image_align.md")]
+        image_align: String,
+        #[doc = include_str!("_docs/attributes/sampling.md")]
+        sampling: String,
 
         image_data: String,
         image_reference: String,
@@ -481,11 +562,26 @@ builder_constructors! {
     svg {
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
+        #[doc = include_str!("_docs/attributes/grid.md")]
+        grid_column: String,
+        grid_row: String,
+        #[doc = include_str!("_docs/attributes/flex_shrink.md")]
+        flex_shrink: String,
        #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
+        #[doc = include_str!("_docs/attributes/aspect_ratio.md")]
+        aspect_ratio: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
+        #[doc = include_str!("_docs/attributes/scale.md")]
+        scale: String,
+        #[doc = include_str!("_docs/attributes/translate.md")]
+        translate: String,
+        #[doc = include_str!("_docs/attributes/skew.md")]
+        skew: String,
+        #[doc = include_str!("_docs/attributes/transform_origin.md")]
+        transform_origin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
 
@@ -585,6 +681,10 @@ pub mod events {
         onrightclick
         #[doc = include_str!("_docs/events/mousedown.md")]
         onmousedown
+        #[doc = include_str!("_docs/events/mouseup.md")]
+        onmouseup
+        #[doc = include_str!("_docs/events/doubleclick.md")]
+        ondoubleclick
         #[doc = include_str!("_docs/events/globalmousedown.md")]
         onglobalmousedown
         #[doc = include_str!("_docs/events/mouseover.md")]
@@ -595,6 +695,10 @@ pub mod events {
         onmouseleave
         #[doc = include_str!("_docs/events/mouseenter.md")]
         onmouseenter
+        #[doc = include_str!("_docs/events/clickcapture.md")]
+        onclickcapture
+        #[doc = include_str!("_docs/events/mousedowncapture.md")]
+        onmousedowncapture
     ];
 
     impl_event! [
@@ -602,6 +706,8 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/wheel.md")]
         onwheel
+        #[doc = include_str!("_docs/events/wheelcapture.md")]
+        onwheelcapture
     ];
 
     impl_event! [
@@ -611,6 +717,10 @@ pub mod events {
         onkeydown
         #[doc = include_str!("_docs/events/keyup.md")]
         onkeyup
+        #[doc = include_str!("_docs/events/keydowncapture.md")]
+        onkeydowncapture
+        #[doc = include_str!("_docs/events/keyupcapture.md")]
+        onkeyupcapture
     ];
 
     impl_event! [
@@ -641,6 +751,10 @@ pub mod events {
         onpointerenter
         #[doc = include_str!("_docs/events/pointerleave.md")]
         onpointerleave
+        #[doc = include_str!("_docs/events/pointerdowncapture.md")]
+        onpointerdowncapture
+        #[doc = include_str!("_docs/events/pointerupcapture.md")]
+        onpointerupcapture
     ];
 
     impl_event! [
@@ -653,4 +767,39 @@ pub mod events {
         #[doc = include_str!("_docs/events/onglobalfilehovercancelled.md")]
         onglobalfilehovercancelled
     ];
+
+    impl_event! [
+        FocusData;
+
+        #[doc = include_str!("_docs/events/focus.md")]
+        onfocus
+        #[doc = include_str!("_docs/events/blur.md")]
+        onblur
+        #[doc = include_str!("_docs/events/focusin.md")]
+        onfocusin
+        #[doc = include_str!("_docs/events/focusout.md")]
+        onfocusout
+    ];
+
+    impl_event! [
+        TouchpadData;
+
+        #[doc = include_str!("_docs/events/pinch.md")]
+        onpinch
+        #[doc = include_str!("_docs/events/rotate.md")]
+        onrotate
+        #[doc = include_str!("_docs/events/pan.md")]
+        onpan
+    ];
+
+    impl_event! [
+        CompositionData;
+
+        #[doc = include_str!("_docs/events/compositionstart.md")]
+        oncompositionstart
+        #[doc = include_str!("_docs/events/compositionupdate.md")]
+        oncompositionupdate
+        #[doc = include_str!("_docs/events/compositionend.md")]
+        oncompositionend
+    ];
 }
@@ -1,16 +1,30 @@
+pub mod composition;
 pub mod file;
+pub mod focus;
 pub mod keyboard;
 pub mod mouse;
 pub mod pointer;
 pub mod touch;
+pub mod touchpad;
 pub mod wheel;
 
+use std::sync::{
+    atomic::{
+        AtomicBool,
+        Ordering,
+    },
+    Arc,
+};
+
+pub use composition::*;
 use dioxus_core::Event;
 pub use file::*;
+pub use focus::*;
 pub use keyboard::*;
 pub use mouse::*;
 pub use pointer::*;
 pub use touch::*;
+pub use touchpad::*;
 pub use wheel::*;
 
 pub type KeyboardEvent = Event<KeyboardData>;
@@ -18,3 +32,57 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type FocusEvent = Event<FocusData>;
+pub type CompositionEvent = Event<CompositionData>;
+pub type TouchpadEvent = Event<TouchpadData>;
+
+/// Default action of an event, e.g. focusing a node when clicked or scrolling a `ScrollView` with the wheel.
+/// It's shared by all the listeners of the same event, in both the capture and bubbling phases,
+/// so any of them can prevent it.
+#[derive(Debug, Clone, Default)]
+pub struct DefaultAction(Arc<AtomicBool>);
+
+impl DefaultAction {
+    /// Prevent the default action.
+    pub fn prevent(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Check whether the default action was prevented.
+    pub fn is_prevented(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for DefaultAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_prevented() == other.is_prevented()
+    }
+}
+
+macro_rules! impl_default_action {
+    ($($data:ty),*) => {
+        $(
+            impl $data {
+                /// Prevent the default action of this event, built-in components and the focus
+                /// management will not react to it.
+                pub fn prevent_default(&self) {
+                    self.default_action.prevent();
+                }
+
+                /// Check whether a listener prevented the default action of this event.
+                pub fn default_prevented(&self) -> bool {
+                    self.default_action.is_prevented()
+                }
+
+                #[doc(hidden)]
+                pub fn with_default_action(mut self, default_action: DefaultAction) -> Self {
+                    self.default_action = default_action;
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_default_action!(MouseData, KeyboardData, WheelData, TouchData, PointerData);
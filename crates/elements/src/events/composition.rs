@@ -0,0 +1,34 @@
+use crate::definitions::PlatformEventData;
+
+/// Data of an IME Composition event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositionData {
+    /// Text being composed, or the committed text once the composition ends (empty if it was cancelled).
+    pub text: String,
+    /// Byte range of the `text` where the IME cursor is, if it should be shown.
+    pub cursor: Option<(usize, usize)>,
+}
+
+impl CompositionData {
+    pub fn new(text: String, cursor: Option<(usize, usize)>) -> Self {
+        Self { text, cursor }
+    }
+}
+
+impl CompositionData {
+    /// Get the composition text.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the byte range of the IME cursor in the composition text.
+    pub fn get_cursor(&self) -> Option<(usize, usize)> {
+        self.cursor
+    }
+}
+
+impl From<&PlatformEventData> for CompositionData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<CompositionData>().cloned().unwrap()
+    }
+}
@@ -0,0 +1,36 @@
+use accesskit::NodeId as AccessibilityId;
+
+use crate::definitions::PlatformEventData;
+
+/// Data of a Focus event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusData {
+    /// Node that had the focus before, if any.
+    pub previous: Option<AccessibilityId>,
+    /// Node that has the focus now, if any.
+    pub next: Option<AccessibilityId>,
+}
+
+impl FocusData {
+    pub fn new(previous: Option<AccessibilityId>, next: Option<AccessibilityId>) -> Self {
+        Self { previous, next }
+    }
+}
+
+impl FocusData {
+    /// Get the Node that had the focus before this event.
+    pub fn get_previous(&self) -> Option<AccessibilityId> {
+        self.previous
+    }
+
+    /// Get the Node that has the focus after this event.
+    pub fn get_next(&self) -> Option<AccessibilityId> {
+        self.next
+    }
+}
+
+impl From<&PlatformEventData> for FocusData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<FocusData>().cloned().unwrap()
+    }
+}
@@ -8,7 +8,10 @@ use winit::keyboard::{
     NamedKey,
 };
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::DefaultAction,
+};
 
 // Return the equivalent of Winit `ModifiersState` in keyboard_types
 pub fn map_winit_modifiers(modifiers: keyboard::ModifiersState) -> Modifiers {
@@ -322,6 +325,7 @@ pub struct KeyboardData {
     pub key: Key,
     pub code: Code,
     pub modifiers: Modifiers,
+    pub(crate) default_action: DefaultAction,
 }
 
 impl KeyboardData {
@@ -330,6 +334,7 @@ impl KeyboardData {
             key,
             code,
             modifiers,
+            default_action: DefaultAction::default(),
         }
     }
 }
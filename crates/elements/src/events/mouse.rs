@@ -1,7 +1,10 @@
 use torin::geometry::CursorPoint;
 pub use winit::event::MouseButton;
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::DefaultAction,
+};
 
 /// Data of a Mouse event.
 #[derive(Debug, Clone, PartialEq)]
@@ -9,6 +12,8 @@ pub struct MouseData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub trigger_button: Option<MouseButton>,
+    pub click_count: u32,
+    pub(crate) default_action: DefaultAction,
 }
 
 impl MouseData {
@@ -16,11 +21,14 @@ impl MouseData {
         screen_coordinates: CursorPoint,
         element_coordinates: CursorPoint,
         trigger_button: Option<MouseButton>,
+        click_count: u32,
     ) -> Self {
         Self {
             screen_coordinates,
             element_coordinates,
             trigger_button,
+            click_count,
+            default_action: DefaultAction::default(),
         }
     }
 }
@@ -40,6 +48,12 @@ impl MouseData {
     pub fn get_trigger_button(&self) -> Option<MouseButton> {
         self.trigger_button
     }
+
+    /// Get how many consecutive times the button was pressed, e.g. `2` for a double click.
+    /// This is `0` for events not caused by a button, like `mouseover`.
+    pub fn get_click_count(&self) -> u32 {
+        self.click_count
+    }
 }
 
 impl From<&PlatformEventData> for MouseData {
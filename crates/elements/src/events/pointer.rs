@@ -5,7 +5,10 @@ use winit::event::{
     TouchPhase,
 };
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::DefaultAction,
+};
 
 /// The type of device that triggered a Pointer event.
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -26,6 +29,7 @@ pub struct PointerData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub pointer_type: PointerType,
+    pub(crate) default_action: DefaultAction,
 }
 
 impl PointerData {
@@ -38,6 +42,7 @@ impl PointerData {
             screen_coordinates,
             element_coordinates,
             pointer_type: point_type,
+            default_action: DefaultAction::default(),
         }
     }
 }
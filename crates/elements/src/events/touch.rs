@@ -4,7 +4,10 @@ pub use winit::event::{
     TouchPhase,
 };
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::DefaultAction,
+};
 
 /// Data of a Touch event.
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +17,7 @@ pub struct TouchData {
     pub finger_id: u64,
     pub phase: TouchPhase,
     pub force: Option<Force>,
+    pub(crate) default_action: DefaultAction,
 }
 
 impl TouchData {
@@ -30,6 +34,7 @@ impl TouchData {
             finger_id,
             phase,
             force,
+            default_action: DefaultAction::default(),
         }
     }
 
@@ -0,0 +1,68 @@
+use torin::geometry::CursorPoint;
+use winit::event::TouchPhase;
+
+use crate::definitions::PlatformEventData;
+
+/// Gesture made with a touchpad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TouchpadGesture {
+    /// Zoom in (positive) or out (negative), e.g. `0.1` means 10% bigger.
+    Pinch { delta: f64 },
+    /// Rotation in degrees, positive is counterclockwise.
+    Rotate { delta: f64 },
+    /// Two fingers moving in the same direction, in pixels.
+    Pan { delta_x: f64, delta_y: f64 },
+}
+
+/// Data of a Touchpad gesture event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TouchpadData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub gesture: TouchpadGesture,
+    pub phase: TouchPhase,
+}
+
+impl TouchpadData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        gesture: TouchpadGesture,
+        phase: TouchPhase,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            gesture,
+            phase,
+        }
+    }
+}
+
+impl TouchpadData {
+    /// Get the mouse coordinates relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the mouse coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get the gesture and its deltas.
+    pub fn get_gesture(&self) -> TouchpadGesture {
+        self.gesture
+    }
+
+    /// Get the phase of the gesture.
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
+}
+
+impl From<&PlatformEventData> for TouchpadData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<TouchpadData>().cloned().unwrap()
+    }
+}
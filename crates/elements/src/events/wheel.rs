@@ -1,4 +1,19 @@
-use crate::definitions::PlatformEventData;
+use winit::event::TouchPhase;
+
+use crate::{
+    definitions::PlatformEventData,
+    events::DefaultAction,
+};
+
+/// Unit in which the device reported the deltas of a Wheel event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WheelDeltaMode {
+    /// Pixels, e.g. from a touchpad.
+    #[default]
+    Pixel,
+    /// Lines, e.g. from the notches of a mouse wheel.
+    Line,
+}
 
 /// Data of a Wheel event.
 #[derive(Debug, Clone, PartialEq)]
@@ -6,24 +21,44 @@ pub struct WheelData {
     #[allow(dead_code)]
     delta_x: f64,
     delta_y: f64,
+    delta_mode: WheelDeltaMode,
+    phase: TouchPhase,
+    pub(crate) default_action: DefaultAction,
 }
 
 impl WheelData {
-    pub fn new(delta_x: f64, delta_y: f64) -> Self {
-        Self { delta_x, delta_y }
+    pub fn new(delta_x: f64, delta_y: f64, delta_mode: WheelDeltaMode, phase: TouchPhase) -> Self {
+        Self {
+            delta_x,
+            delta_y,
+            delta_mode,
+            phase,
+            default_action: DefaultAction::default(),
+        }
     }
 }
 
 impl WheelData {
-    /// Get the X delta.
+    /// Get the X delta, in pixels.
     pub fn get_delta_x(&self) -> f64 {
         self.delta_x
     }
 
-    /// Get the Y delta.
+    /// Get the Y delta, in pixels.
     pub fn get_delta_y(&self) -> f64 {
         self.delta_y
     }
+
+    /// Get the unit in which the device reported the deltas.
+    /// Lines are always converted into pixels, so this is only useful to tell mouse wheels and touchpads apart.
+    pub fn get_delta_mode(&self) -> WheelDeltaMode {
+        self.delta_mode
+    }
+
+    /// Get the phase of the scroll, touchpads start and end their scrolls while mouse wheels are always moving.
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
 }
 
 impl From<&PlatformEventData> for WheelData {
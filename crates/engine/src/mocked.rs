@@ -140,6 +140,30 @@ impl Shader {
     ) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn radial_gradient<'a>(
+        _center: impl Into<Point>,
+        _radius: f32,
+        _colors: impl Into<GradientShaderColors<'a>>,
+        _pos: impl Into<Option<&'a [f32]>>,
+        _mode: TileMode,
+        _flags: impl Into<Option<GradientFlags>>,
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn sweep_gradient<'a>(
+        _center: impl Into<Point>,
+        _colors: impl Into<GradientShaderColors<'a>>,
+        _pos: impl Into<Option<&'a [f32]>>,
+        _mode: TileMode,
+        _angles: impl Into<Option<(f32, f32)>>,
+        _flags: impl Into<Option<GradientFlags>>,
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum TileMode {
@@ -171,6 +195,46 @@ impl Matrix {
     pub fn set_rotate(&mut self, _degrees: f32, _pivot: impl Into<Option<Point>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn pre_translate(&mut self, _delta: impl Into<Point>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_rotate(&mut self, _degrees: f32, _pivot: impl Into<Option<Point>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_scale(
+        &mut self,
+        (_sx, _sy): (f32, f32),
+        _pivot: impl Into<Option<Point>>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_skew(
+        &mut self,
+        (_kx, _ky): (f32, f32),
+        _pivot: impl Into<Option<Point>>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_concat(&mut self, _other: &Self) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn invert(&self) -> Option<Matrix> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_point(&self, _point: impl Into<Point>) -> Point {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_rect(&self, _rect: Rect) -> (Rect, bool) {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -408,6 +472,10 @@ impl TextStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_foreground_paint(&mut self, _paint: &Paint) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clear_foreground_color(&mut self) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -420,6 +488,10 @@ impl TextStyle {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_background_paint(&mut self, _paint: &Paint) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clear_background_color(&mut self) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -604,6 +676,18 @@ impl Paint {
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn set_path_effect(&mut self, _path_effect: impl Into<Option<PathEffect>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_image_filter(&mut self, _image_filter: impl Into<Option<ImageFilter>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum PaintStyle {
@@ -612,6 +696,20 @@ pub enum PaintStyle {
     StrokeAndFill = 2,
 }
 
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+pub struct PathEffect;
+
+impl PathEffect {
+    pub fn dash(_intervals: &[f32], _phase: f32) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct FontStyle;
 
 impl FontStyle {
@@ -954,7 +1052,7 @@ pub struct PlaceholderStyle;
 pub struct Canvas;
 
 impl Canvas {
-    pub fn save(&self) {
+    pub fn save(&self) -> usize {
         unimplemented!("This is mocked")
     }
 
@@ -962,6 +1060,14 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn restore_to_count(&self, _count: usize) {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn reset_matrix(&self) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn concat(&self, _matrix: &Matrix) {
         unimplemented!("This is mocked")
     }
@@ -981,6 +1087,17 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn draw_image_rect_with_sampling_options(
+        &self,
+        _image: &Image,
+        _src: Option<(&Rect, SrcRectConstraint)>,
+        _dst: Rect,
+        _sampling: impl Into<SamplingOptions>,
+        _paint: &Paint,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn draw_rect(&self, _rect: Rect, _paint: &Paint) -> &Self {
         unimplemented!("This is mocked")
     }
@@ -1021,6 +1138,62 @@ impl Canvas {
     pub fn save_layer_alpha_f(&self, bounds: impl Into<Option<Rect>>, alpha: f32) -> usize {
         unimplemented!("This is mocked")
     }
+
+    pub fn save_layer(&self, _layer_rec: &SaveLayerRec) -> usize {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Default)]
+pub struct SaveLayerRec<'a> {
+    _data: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> SaveLayerRec<'a> {
+    pub fn bounds(self, _bounds: &'a Rect) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn paint(self, _paint: &'a Paint) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn backdrop(self, _backdrop: &'a ImageFilter) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct ImageFilter;
+
+pub mod image_filters {
+    use super::{
+        ImageFilter,
+        Rect,
+        TileMode,
+    };
+
+    pub struct CropRect;
+
+    impl From<Option<Rect>> for CropRect {
+        fn from(_rect: Option<Rect>) -> Self {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    impl From<&Rect> for CropRect {
+        fn from(_rect: &Rect) -> Self {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    pub fn blur(
+        _sigma: (f32, f32),
+        _tile_mode: impl Into<Option<TileMode>>,
+        _input: impl Into<Option<ImageFilter>>,
+        _crop_rect: impl Into<CropRect>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1128,6 +1301,14 @@ impl Rect {
     pub fn new(_left: f32, _top: f32, _right: f32, _bottom: f32) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn width(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Image;
@@ -1136,6 +1317,23 @@ impl Image {
     pub fn from_encoded(_data: Data) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn encode<'a>(
+        &self,
+        _context: impl Into<Option<&'a mut DirectContext>>,
+        _format: EncodedImageFormat,
+        _quality: impl Into<Option<u32>>,
+    ) -> Option<Data> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Data;
@@ -1148,6 +1346,10 @@ impl Data {
     pub unsafe fn new_bytes(_bytes: &[u8]) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -1180,6 +1382,52 @@ impl FilterMode {
     pub const Last: FilterMode = FilterMode::Linear;
 }
 
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum MipmapMode {
+    None = 0,
+    Nearest = 1,
+    Linear = 2,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CubicResampler {
+    pub b: f32,
+    pub c: f32,
+}
+
+impl CubicResampler {
+    pub fn mitchell() -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn catmull_rom() -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum SrcRectConstraint {
+    Strict = 0,
+    Fast = 1,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct SamplingOptions;
+
+impl SamplingOptions {
+    pub fn new(_filter_mode: FilterMode, _mipmap_mode: MipmapMode) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+impl From<CubicResampler> for SamplingOptions {
+    fn from(_cubic: CubicResampler) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct Path;
 
 impl Path {
@@ -1187,6 +1435,14 @@ impl Path {
         unimplemented!("This is mocked")
     }
 
+    pub fn add_rect(
+        &mut self,
+        _rect: Rect,
+        _dir_start: Option<(PathDirection, usize)>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn add_path(
         &mut self,
         _src: &Path,
@@ -1278,6 +1534,10 @@ impl RRect {
     pub fn with_outset(&self, _delta: impl Into<Point>) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn rect(&self) -> &Rect {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1378,6 +1638,46 @@ impl Surface {
         unimplemented!("This is mocked")
     }
 
+    pub fn new_surface_with_dimensions(&mut self, _dimensions: (i32, i32)) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn image_snapshot(&mut self) -> Image {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn read_pixels(
+        &mut self,
+        _dst_info: &ImageInfo,
+        _dst_pixels: &mut [u8],
+        _dst_row_bytes: usize,
+        _src: (i32, i32),
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn direct_context(&self) -> Option<DirectContext> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw(
+        &mut self,
+        _canvas: &Canvas,
+        _offset: impl Into<Point>,
+        _sampling: impl Into<SamplingOptions>,
+        _paint: Option<&Paint>,
+    ) {
+        unimplemented!("This is mocked")
+    }
+
     pub fn from_backend_render_target(
         _context: &mut RecordingContext,
         _backend_render_target: &BackendRenderTarget,
@@ -1390,12 +1690,39 @@ impl Surface {
     }
 }
 
+pub fn raster_n32_premul(_size: (i32, i32)) -> Option<Surface> {
+    unimplemented!("This is mocked")
+}
+
 pub struct ColorSpace;
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(i32)]
 pub enum ColorType {
     RGBA8888 = 4,
+    BGRA8888 = 6,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
+}
+
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        _dimensions: (i32, i32),
+        _ct: ColorType,
+        _at: AlphaType,
+        _cs: impl Into<Option<ColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct SurfaceProps;
@@ -1575,6 +1902,7 @@ pub fn set_resource_cache_single_allocation_byte_limit(new_limit: Option<usize>)
     unimplemented!("This is mocked")
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum EncodedImageFormat {
     BMP = 0,
     GIF = 1,
@@ -1,4 +1,5 @@
 pub use skia_safe::{
+    canvas::SaveLayerRec,
     font_style::{
         Slant,
         Weight,
@@ -23,6 +24,7 @@ pub use skia_safe::{
         set_resource_cache_single_allocation_byte_limit,
         set_resource_cache_total_bytes_limit,
     },
+    image_filters,
     path::ArcSize,
     rrect::Corner,
     runtime_effect::Uniform,
@@ -55,12 +57,14 @@ pub use skia_safe::{
         TextStyle,
         TypefaceFontProvider,
     },
+    AlphaType,
     BlurStyle,
     Canvas,
     ClipOp,
     Color,
     ColorSpace,
     ColorType,
+    CubicResampler,
     Data,
     EncodedImageFormat,
     FilterMode,
@@ -69,16 +73,22 @@ pub use skia_safe::{
     FontStyle,
     IRect,
     Image,
+    ImageFilter,
+    ImageInfo,
     MaskFilter,
     Matrix,
+    MipmapMode,
     Paint,
+    PaintCap,
     PaintStyle,
     Path,
     PathDirection,
+    PathEffect,
     Point,
     RRect,
     Rect,
     RuntimeEffect,
+    SamplingOptions,
     Shader,
     Surface,
     TileMode,
@@ -0,0 +1,441 @@
+//! Render your app without a window, e.g. to take screenshots of components in the docs or CI.
+//!
+//! ```rust,no_run
+//! use freya::prelude::*;
+//!
+//! fn app() -> Element {
+//!     rsx!(
+//!         rect {
+//!             width: "100%",
+//!             height: "100%",
+//!             background: "rgb(35, 35, 35)",
+//!             color: "white",
+//!             label { "Hello, World!" }
+//!         }
+//!     )
+//! }
+//!
+//! let png = freya::headless::render(app, (300.0, 200.0), 2.0).unwrap();
+//! std::fs::write("./screenshot.png", png).unwrap();
+//! ```
+//!
+//! The sync functions start their own Tokio runtime, so from async code (e.g. a `#[tokio::test]`)
+//! use [render_async] and [save_async] instead.
+//!
+//! The `headless_screenshot` example works as a command line tool to take a screenshot of an app:
+//!
+//! ```sh
+//! cargo run --example headless_screenshot -- ./screenshot.png 600 400 2
+//! ```
+
+use std::{
+    fs::File,
+    future::Future,
+    io::{
+        self,
+        Write,
+    },
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use dioxus_core::VirtualDom;
+use freya_common::EventMessage;
+use freya_core::prelude::*;
+use freya_engine::prelude::*;
+use freya_renderer::EmbeddedFonts;
+use tokio::{
+    runtime::Builder,
+    sync::{
+        broadcast,
+        mpsc::{
+            unbounded_channel,
+            UnboundedReceiver,
+            UnboundedSender,
+        },
+        watch,
+    },
+    time::timeout,
+};
+use torin::geometry::{
+    Area,
+    Size2D,
+};
+
+use crate::launch::{
+    with_accessibility,
+    AppComponent,
+};
+
+/// Configuration for a [HeadlessRenderer].
+pub struct HeadlessConfig<'a> {
+    /// Size of the app, in logical pixels.
+    pub size: Size2D,
+    /// Scale factor the app is rendered with.
+    pub scale_factor: f64,
+    /// Color the app is painted on top of.
+    pub background: Color,
+    /// Fonts embedded in the app.
+    pub embedded_fonts: EmbeddedFonts<'a>,
+    /// Fonts used by default.
+    pub default_fonts: Vec<String>,
+    /// Frames to run before the app is rendered.
+    pub frames: usize,
+    /// How long each frame waits for the app to update, e.g. for animations or effects to run.
+    pub frame_duration: Duration,
+    /// Format the rendered images are encoded with.
+    pub format: EncodedImageFormat,
+}
+
+impl Default for HeadlessConfig<'_> {
+    fn default() -> Self {
+        Self {
+            size: Size2D::new(600.0, 600.0),
+            scale_factor: 1.0,
+            background: Color::WHITE,
+            embedded_fonts: Default::default(),
+            default_fonts: default_fonts(),
+            frames: 5,
+            frame_duration: Duration::from_millis(16),
+            format: EncodedImageFormat::PNG,
+        }
+    }
+}
+
+impl<'a> HeadlessConfig<'a> {
+    pub fn new() -> Self {
+        HeadlessConfig::default()
+    }
+
+    /// Specify the size of the app.
+    pub fn with_size(mut self, size: impl Into<Size2D>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Specify the scale factor.
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Specify the background color.
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Embed a font.
+    pub fn with_font(mut self, font_name: &'a str, font: &'a [u8]) -> Self {
+        self.embedded_fonts.push((font_name, font));
+        self
+    }
+
+    /// Clear default fonts.
+    pub fn without_default_fonts(mut self) -> Self {
+        self.default_fonts.clear();
+        self
+    }
+
+    /// Register a default font. Will be used if found.
+    pub fn with_default_font(mut self, font_name: &str) -> Self {
+        self.default_fonts.push(font_name.to_string());
+        self
+    }
+
+    /// Specify how many frames to run before rendering.
+    pub fn with_frames(mut self, frames: usize) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Specify how long each frame waits for the app to update.
+    pub fn with_frame_duration(mut self, frame_duration: Duration) -> Self {
+        self.frame_duration = frame_duration;
+        self
+    }
+
+    /// Specify the format of the rendered images.
+    pub fn with_format(mut self, format: EncodedImageFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Runs an app without a window and renders it into images.
+pub struct HeadlessRenderer {
+    vdom: VirtualDom,
+    sdom: SafeDOM,
+    font_collection: FontCollection,
+    font_mgr: FontMgr,
+    images_cache: ImagesCache,
+    platform_event_emitter: UnboundedSender<EventMessage>,
+    platform_event_receiver: UnboundedReceiver<EventMessage>,
+    platform_sender: NativePlatformSender,
+    platform_receiver: NativePlatformReceiver,
+    ticker_sender: broadcast::Sender<()>,
+    size: Size2D,
+    scale_factor: f64,
+    background: Color,
+    default_fonts: Vec<String>,
+    frame_duration: Duration,
+    format: EncodedImageFormat,
+}
+
+impl HeadlessRenderer {
+    /// Create the app and make its first build.
+    /// Must be called inside a Tokio runtime.
+    pub fn new(app: AppComponent, config: HeadlessConfig) -> Self {
+        let mut font_collection = FontCollection::new();
+        let def_mgr = FontMgr::default();
+
+        let mut provider = TypefaceFontProvider::new();
+
+        for (font_name, font_data) in config.embedded_fonts {
+            let ft_type = def_mgr.new_from_data(font_data, None).unwrap();
+            provider.register_typeface(ft_type, Some(font_name));
+        }
+
+        let font_mgr: FontMgr = provider.into();
+        font_collection.set_default_font_manager(def_mgr, None);
+        font_collection.set_dynamic_font_manager(font_mgr.clone());
+
+        let (platform_event_emitter, platform_event_receiver) = unbounded_channel();
+        let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
+            focused_id: ACCESSIBILITY_ROOT_ID,
+            preferred_theme: PreferredTheme::default(),
+            navigation_mode: NavigationMode::default(),
+            information: PlatformInformation::new(
+                config.size * config.scale_factor as f32,
+                false,
+                false,
+                false,
+            ),
+            scale_factor: config.scale_factor,
+        });
+
+        let mut renderer = Self {
+            vdom: with_accessibility(app),
+            sdom: SafeDOM::new(FreyaDOM::default()),
+            font_collection,
+            font_mgr,
+            images_cache: ImagesCache::default(),
+            platform_event_emitter,
+            platform_event_receiver,
+            platform_sender,
+            platform_receiver,
+            ticker_sender: broadcast::channel(5).0,
+            size: config.size,
+            scale_factor: config.scale_factor,
+            background: config.background,
+            default_fonts: config.default_fonts,
+            frame_duration: config.frame_duration,
+            format: config.format,
+        };
+
+        renderer.init_dom();
+
+        renderer
+    }
+
+    /// Provide the platform utilities and make the first build of the DOM.
+    fn init_dom(&mut self) {
+        self.vdom
+            .insert_any_root_context(Box::new(self.platform_event_emitter.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.platform_receiver.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+
+        self.sdom
+            .get_mut()
+            .init_dom(&mut self.vdom, self.scale_factor as f32);
+
+        self.process_layout();
+    }
+
+    /// Size of the rendered images, in physical pixels.
+    fn physical_size(&self) -> Size2D {
+        self.size * self.scale_factor as f32
+    }
+
+    /// Measure the layout.
+    fn process_layout(&mut self) {
+        process_layout(
+            &self.sdom.get(),
+            Area::from_size(self.physical_size()),
+            &mut self.font_collection,
+            self.scale_factor,
+            &self.default_fonts,
+        );
+    }
+
+    /// Change the size of the app.
+    pub fn resize(&mut self, size: impl Into<Size2D>) {
+        self.size = size.into();
+        let viewport_size = self.physical_size();
+        self.platform_sender.send_modify(|state| {
+            state.information.viewport_size = viewport_size;
+        });
+        self.sdom.get().layout().reset();
+        self.process_layout();
+    }
+
+    /// Let the app update for one frame.
+    pub async fn next_frame(&mut self) {
+        while let Ok(event) = self.platform_event_receiver.try_recv() {
+            match event {
+                EventMessage::RemeasureTextGroup(text_measurement) => {
+                    self.sdom
+                        .get()
+                        .measure_paragraphs(text_measurement, self.scale_factor);
+                }
+                EventMessage::RenderNodeSnapshot(request) => {
+                    let snapshot = render_node_snapshot(
+                        &self.sdom.get(),
+                        request.node_id,
+                        &request.config,
+                        &mut self.font_collection,
+                        &self.font_mgr,
+                        &mut self.images_cache,
+                        &self.default_fonts,
+                        self.scale_factor as f32,
+                    );
+                    (request.callback)(snapshot);
+                }
+                _ => {}
+            }
+        }
+
+        self.ticker_sender.send(()).ok();
+
+        timeout(self.frame_duration, self.vdom.wait_for_work())
+            .await
+            .ok();
+
+        let (_, must_relayout) = self
+            .sdom
+            .get_mut()
+            .render_mutations(&mut self.vdom, self.scale_factor as f32);
+
+        if must_relayout {
+            self.process_layout();
+        }
+    }
+
+    /// Let the app update for the given number of frames.
+    pub async fn run_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.next_frame().await;
+        }
+    }
+
+    /// Render the app in its current state into an encoded image.
+    /// Returns `None` if the image can't be encoded with the configured format.
+    pub fn render(&mut self) -> Option<Vec<u8>> {
+        let fdom = self.sdom.get();
+        let (width, height) = self.physical_size().ceil().to_i32().to_tuple();
+
+        let mut surface = raster_n32_premul((width.max(1), height.max(1)))
+            .expect("Failed to create the surface.");
+        surface.canvas().clear(self.background);
+
+        let mut skia_renderer = SkiaRenderer {
+            canvas: surface.canvas(),
+            font_collection: &mut self.font_collection,
+            font_manager: &self.font_mgr,
+            images_cache: &mut self.images_cache,
+            matrices: Vec::default(),
+            opacities: Vec::default(),
+            blurs: Vec::default(),
+            default_fonts: &self.default_fonts,
+            scale_factor: self.scale_factor as f32,
+        };
+
+        process_render(
+            &fdom,
+            &Damage::Full,
+            |fdom, node_id, layout_node, layout, is_damaged| {
+                if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
+                    skia_renderer.render(layout_node, &dioxus_node, false, layout, is_damaged);
+                }
+            },
+        );
+
+        self.images_cache.clean(fdom.rdom());
+
+        let image = surface.image_snapshot();
+        let mut context = surface.direct_context();
+        let data = image.encode(context.as_mut(), self.format, None)?;
+
+        Some(data.as_bytes().to_vec())
+    }
+
+    /// Render the app in its current state into an image file.
+    pub fn save(&mut self, path: impl Into<PathBuf>) -> io::Result<()> {
+        let data = self.render().ok_or_else(unsupported_format)?;
+        File::create(path.into())?.write_all(&data)
+    }
+}
+
+fn unsupported_format() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "The image can't be encoded with the configured format.",
+    )
+}
+
+/// Render the app with the given size and scale factor into a PNG image.
+pub fn render(app: AppComponent, size: impl Into<Size2D>, scale_factor: f64) -> Option<Vec<u8>> {
+    render_with_config(
+        app,
+        HeadlessConfig::default()
+            .with_size(size)
+            .with_scale_factor(scale_factor),
+    )
+}
+
+/// Render the app into an image as described by the given [HeadlessConfig].
+/// Returns `None` if the image can't be encoded with the configured format.
+///
+/// Must not be called inside a Tokio runtime, use [render_async] there.
+pub fn render_with_config(app: AppComponent, config: HeadlessConfig) -> Option<Vec<u8>> {
+    block_on(render_async(app, config))
+}
+
+/// Render the app into an image file as described by the given [HeadlessConfig].
+///
+/// Must not be called inside a Tokio runtime, use [save_async] there.
+pub fn save(app: AppComponent, config: HeadlessConfig, path: impl Into<PathBuf>) -> io::Result<()> {
+    block_on(save_async(app, config, path))
+}
+
+/// Render the app into an image as described by the given [HeadlessConfig].
+/// Returns `None` if the image can't be encoded with the configured format.
+pub async fn render_async(app: AppComponent, config: HeadlessConfig<'_>) -> Option<Vec<u8>> {
+    let frames = config.frames;
+    let mut renderer = HeadlessRenderer::new(app, config);
+    renderer.run_frames(frames).await;
+    renderer.render()
+}
+
+/// Render the app into an image file as described by the given [HeadlessConfig].
+pub async fn save_async(
+    app: AppComponent,
+    config: HeadlessConfig<'_>,
+    path: impl Into<PathBuf>,
+) -> io::Result<()> {
+    let data = render_async(app, config)
+        .await
+        .ok_or_else(unsupported_format)?;
+    File::create(path.into())?.write_all(&data)
+}
+
+fn block_on<T>(future: impl Future<Output = T>) -> T {
+    Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
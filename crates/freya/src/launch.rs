@@ -230,10 +230,10 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
     DesktopRenderer::launch(vdom, sdom, config, devtools, hovered_node);
 }
 
-#[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
 use dioxus_core::VirtualDom;
-#[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
-fn with_accessibility(app: AppComponent) -> VirtualDom {
+
+#[cfg_attr(all(feature = "devtools", debug_assertions), allow(dead_code))]
+pub(crate) fn with_accessibility(app: AppComponent) -> VirtualDom {
     use dioxus::prelude::Props;
     use dioxus_core::fc_to_builder;
     use dioxus_core_macro::rsx;
@@ -257,4 +257,4 @@ fn with_accessibility(app: AppComponent) -> VirtualDom {
     VirtualDom::new_with_props(Root, RootProps { app })
 }
 
-type AppComponent = fn() -> Element;
+pub(crate) type AppComponent = fn() -> Element;
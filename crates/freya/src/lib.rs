@@ -16,6 +16,7 @@
 //! - [Theming](self::_docs::theming)
 //! - [Hot reload](self::_docs::hot_reload)
 //! - [Testing](freya_testing)
+//! - [Headless rendering](self::headless)
 //! - [Animating](freya_hooks::use_animation)
 //! - [Devtools](self::_docs::devtools)
 //!
@@ -62,6 +63,9 @@ pub use freya_elements::_docs as elements_docs;
 /// Launch your app.
 pub mod launch;
 
+/// Render your app without a window.
+pub mod headless;
+
 /// Collection of basic components.
 pub mod components {
     pub use freya_components::*;
@@ -3,9 +3,12 @@ use std::time::{
     Instant,
 };
 
-use freya_core::plugins::{
-    FreyaPlugin,
-    PluginEvent,
+use freya_core::{
+    damage::Damage,
+    plugins::{
+        FreyaPlugin,
+        PluginEvent,
+    },
 };
 use freya_engine::prelude::{
     Color,
@@ -32,6 +35,7 @@ pub struct PerformanceOverlayPlugin {
     finished_dom_updates: Option<Duration>,
     fps_historic: Vec<usize>,
     max_fps: usize,
+    damage: Damage,
 }
 
 impl FreyaPlugin for PerformanceOverlayPlugin {
@@ -46,6 +50,7 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                 self.finished_dom_updates = Some(self.started_dom_updates.unwrap().elapsed())
             }
             PluginEvent::BeforeRender { .. } => self.started_render = Some(Instant::now()),
+            PluginEvent::Damage(damage) => self.damage = (*damage).clone(),
             PluginEvent::AfterRender {
                 canvas,
                 font_collection,
@@ -123,6 +128,30 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                     14.0,
                 );
 
+                // Repainted areas
+                add_text(
+                    &mut paragraph_builder,
+                    match &self.damage {
+                        Damage::None => "Nothing Repainted \n".to_string(),
+                        Damage::Partial(areas) => format!("{} Repainted Areas \n", areas.len()),
+                        Damage::Full => "Fully Repainted \n".to_string(),
+                    },
+                    14.0,
+                );
+
+                // Outline the repainted areas
+                let mut damage_paint = Paint::default();
+                damage_paint.set_anti_alias(true);
+                damage_paint.set_style(PaintStyle::Stroke);
+                damage_paint.set_stroke_width(2.0);
+                damage_paint.set_color(Color::from_argb(180, 255, 0, 0));
+                for area in self.damage.areas() {
+                    canvas.draw_rect(
+                        Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                        &damage_paint,
+                    );
+                }
+
                 let mut paragraph = paragraph_builder.build();
                 paragraph.layout(f32::MAX);
                 paragraph.paint(canvas, (5.0, 0.0));
@@ -0,0 +1,69 @@
+use freya::{
+    headless::{
+        render_async,
+        HeadlessConfig,
+    },
+    prelude::*,
+};
+use freya_engine::prelude::*;
+
+#[tokio::test]
+pub async fn render_app() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                direction: "horizontal",
+                rect {
+                    width: "50%",
+                    height: "100%",
+                    background: "rgb(255, 0, 0)",
+                }
+                rect {
+                    width: "50%",
+                    height: "100%",
+                    background: "rgb(0, 0, 255)",
+                }
+            }
+        )
+    }
+
+    let png = render_async(
+        app,
+        HeadlessConfig::new()
+            .with_size((100.0, 50.0))
+            .with_scale_factor(2.0),
+    )
+    .await
+    .unwrap();
+
+    let image = Image::from_encoded(Data::new_copy(&png)).unwrap();
+    assert_eq!((image.width(), image.height()), (200, 100));
+
+    // Decode the image so its pixels can be read
+    let mut surface = raster_n32_premul((image.width(), image.height())).unwrap();
+    surface.canvas().draw_image(&image, (0.0, 0.0), None);
+    let snapshot = surface.image_snapshot();
+    let pixels = snapshot.peek_pixels().unwrap();
+
+    assert_eq!(pixels.get_color((50, 50)), Color::RED);
+    assert_eq!(pixels.get_color((150, 50)), Color::BLUE);
+}
+
+#[tokio::test]
+pub async fn render_unsupported_format() {
+    fn app() -> Element {
+        rsx!(rect {})
+    }
+
+    let image = render_async(
+        app,
+        HeadlessConfig::new()
+            .with_size((10.0, 10.0))
+            .with_format(EncodedImageFormat::GIF),
+    )
+    .await;
+
+    assert!(image.is_none());
+}
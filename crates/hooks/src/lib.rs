@@ -15,6 +15,7 @@ mod use_editable;
 mod use_focus;
 mod use_init_native_platform;
 mod use_node;
+mod use_node_snapshot;
 mod use_platform;
 mod use_platform_information;
 mod use_preferred_theme;
@@ -38,6 +39,7 @@ pub use use_editable::*;
 pub use use_focus::*;
 pub use use_init_native_platform::*;
 pub use use_node::*;
+pub use use_node_snapshot::*;
 pub use use_platform::*;
 pub use use_platform_information::*;
 pub use use_preferred_theme::*;
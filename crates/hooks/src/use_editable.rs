@@ -18,6 +18,7 @@ use freya_common::{
 };
 use freya_elements::events::{
     Code,
+    CompositionData,
     KeyboardData,
     MouseData,
 };
@@ -46,6 +47,8 @@ pub enum EditableEvent {
     MouseDown(Rc<MouseData>, usize),
     KeyDown(Rc<KeyboardData>),
     KeyUp(Rc<KeyboardData>),
+    Composition(Rc<CompositionData>),
+    CompositionEnd,
 }
 
 /// How the editable content must behave.
@@ -122,6 +125,7 @@ pub struct UseEditable {
     pub(crate) editor: Signal<RopeEditor>,
     pub(crate) cursor_reference: Signal<CursorReference>,
     pub(crate) dragging: Signal<TextDragging>,
+    pub(crate) preedit: Signal<Option<CompositionData>>,
     pub(crate) platform: UsePlatform,
     pub(crate) allow_tabs: bool,
 }
@@ -137,6 +141,11 @@ impl UseEditable {
         &mut self.editor
     }
 
+    /// Text being composed with the IME, to be shown at the cursor position until it's committed.
+    pub fn preedit(&self) -> Option<CompositionData> {
+        self.preedit.read().clone()
+    }
+
     /// Create a cursor attribute.
     pub fn cursor_attr(&self) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::CursorReference(
@@ -237,6 +246,18 @@ impl UseEditable {
                     *self.dragging.write() = TextDragging::None;
                 }
 
+                None
+            }
+            EditableEvent::Composition(e) => {
+                *self.preedit.write() = (!e.text.is_empty()).then(|| e.as_ref().clone());
+
+                None
+            }
+            EditableEvent::CompositionEnd => {
+                if self.preedit.peek().is_some() {
+                    *self.preedit.write() = None;
+                }
+
                 None
             }
         };
@@ -376,6 +397,7 @@ pub fn use_editable(initializer: impl Fn() -> EditableConfig, mode: EditableMode
             editor,
             cursor_reference: Signal::new(cursor_reference.clone()),
             dragging,
+            preedit: Signal::new(None),
             platform,
             allow_tabs: config.allow_tabs,
         }
@@ -0,0 +1,133 @@
+use dioxus_core::{
+    use_hook,
+    AttributeValue,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+};
+use freya_common::{
+    NodeReferenceLayout,
+    SnapshotConfig,
+};
+
+use crate::{
+    use_node_signal,
+    use_platform,
+    UsePlatform,
+};
+
+/// Render a Node and its descendants into an image. Created with [use_node_snapshot].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseNodeSnapshot {
+    layout: ReadOnlySignal<NodeReferenceLayout>,
+    platform: UsePlatform,
+}
+
+impl UseNodeSnapshot {
+    /// Render the Node into an image encoded as described by the [SnapshotConfig].
+    /// Returns `None` if the Node hasn't been measured yet or couldn't be rendered.
+    pub async fn snapshot(&self, config: SnapshotConfig) -> Option<Vec<u8>> {
+        let node_id = self.layout.peek().node_id?;
+        self.platform.render_node_snapshot(node_id, config).await
+    }
+}
+
+/// Capture images of a Node, e.g. for drag previews, thumbnails or exporting charts.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (reference, node_snapshot) = use_node_snapshot();
+///
+///     let onclick = move |_| {
+///         spawn(async move {
+///             let png = node_snapshot.snapshot(SnapshotConfig::default()).await;
+///             println!("{:?} bytes", png.map(|png| png.len()));
+///         });
+///     };
+///
+///     rsx!(rect {
+///         reference,
+///         onclick,
+///         background: "red",
+///         width: "100",
+///         height: "100",
+///     })
+/// }
+/// ```
+pub fn use_node_snapshot() -> (AttributeValue, UseNodeSnapshot) {
+    let (reference, layout) = use_node_signal();
+    let platform = use_platform();
+    let node_snapshot = use_hook(|| UseNodeSnapshot { layout, platform });
+
+    (reference, node_snapshot)
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_engine::prelude::{
+        Data,
+        Image,
+    };
+    use freya_testing::prelude::*;
+
+    use crate::use_node_snapshot;
+
+    #[tokio::test]
+    pub async fn node_snapshot() {
+        fn use_node_snapshot_app() -> Element {
+            let (reference, node_snapshot) = use_node_snapshot();
+            let mut size = use_signal(|| None);
+            let size_text = format!("{:?}", size.read());
+
+            let onclick = move |_| {
+                spawn(async move {
+                    let snapshot = node_snapshot
+                        .snapshot(SnapshotConfig {
+                            scale: 2.0,
+                            ..SnapshotConfig::default()
+                        })
+                        .await;
+                    let image = snapshot
+                        .and_then(|snapshot| Image::from_encoded(Data::new_copy(&snapshot)));
+                    size.set(image.map(|image| (image.width(), image.height())));
+                });
+            };
+
+            rsx!(
+                rect {
+                    reference,
+                    onclick,
+                    width: "100",
+                    height: "50",
+                    background: "red",
+                    label {
+                        "{size_text}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_node_snapshot_app);
+
+        // Wait for the Node to be measured
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("None"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("Some((200, 100))"));
+    }
+}
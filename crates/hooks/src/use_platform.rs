@@ -9,10 +9,16 @@ use dioxus_signals::{
     Readable,
     Signal,
 };
-use freya_common::EventMessage;
+use freya_common::{
+    EventMessage,
+    NodeSnapshotRequest,
+    SnapshotConfig,
+};
+use freya_native_core::NodeId;
 use tokio::sync::{
     broadcast,
     mpsc::UnboundedSender,
+    oneshot,
 };
 use winit::{
     event_loop::EventLoopProxy,
@@ -131,6 +137,25 @@ impl UsePlatform {
         }
     }
 
+    /// Render the given Node and its descendants into an image encoded as described by the [SnapshotConfig].
+    /// Returns `None` if the Node couldn't be rendered.
+    pub async fn render_node_snapshot(
+        &self,
+        node_id: NodeId,
+        config: SnapshotConfig,
+    ) -> Option<Vec<u8>> {
+        let (tx, rx) = oneshot::channel();
+        self.send(EventMessage::RenderNodeSnapshot(NodeSnapshotRequest {
+            node_id,
+            config,
+            callback: Box::new(move |snapshot| {
+                tx.send(snapshot).ok();
+            }),
+        }))
+        .ok()?;
+        rx.await.ok().flatten()
+    }
+
     /// Closes the whole app.
     pub fn exit(&self) {
         self.send(EventMessage::ExitApp).ok();
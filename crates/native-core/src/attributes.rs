@@ -11,8 +11,17 @@ pub enum AttributeName {
     Padding,
     Background,
     Border,
+    BorderTop,
+    BorderRight,
+    BorderBottom,
+    BorderLeft,
     BorderAlign,
     Direction,
+    Wrap,
+    GridColumns,
+    GridRows,
+    GridColumn,
+    GridRow,
     Shadow,
     CornerRadius,
     CornerSmoothing,
@@ -26,6 +35,7 @@ pub enum AttributeName {
     CrossAlign,
     TextAlign,
     TextShadow,
+    TextStroke,
     MaxLines,
     LineHeight,
     LetterSpacing,
@@ -35,14 +45,23 @@ pub enum AttributeName {
     DecorationStyle,
     TextOverflow,
     Rotate,
+    Scale,
+    Translate,
+    Skew,
+    TransformOrigin,
     Overflow,
     Margin,
+    Spacing,
+    FlexShrink,
+    AspectRatio,
     Position,
     PositionTop,
     PositionRight,
     PositionBottom,
     PositionLeft,
     Opacity,
+    Blur,
+    BackdropBlur,
     Content,
     Name,
     Focusable,
@@ -64,6 +83,9 @@ pub enum AttributeName {
     HighlightMode,
     ImageReference,
     ImageData,
+    ImageFit,
+    ImageAlign,
+    Sampling,
     SvgData,
     SvgContent,
 }
@@ -82,8 +104,17 @@ impl FromStr for AttributeName {
             "padding" => Ok(AttributeName::Padding),
             "background" => Ok(AttributeName::Background),
             "border" => Ok(AttributeName::Border),
+            "border_top" => Ok(AttributeName::BorderTop),
+            "border_right" => Ok(AttributeName::BorderRight),
+            "border_bottom" => Ok(AttributeName::BorderBottom),
+            "border_left" => Ok(AttributeName::BorderLeft),
             "border_align" => Ok(AttributeName::BorderAlign),
             "direction" => Ok(AttributeName::Direction),
+            "wrap" => Ok(AttributeName::Wrap),
+            "grid_columns" => Ok(AttributeName::GridColumns),
+            "grid_rows" => Ok(AttributeName::GridRows),
+            "grid_column" => Ok(AttributeName::GridColumn),
+            "grid_row" => Ok(AttributeName::GridRow),
             "shadow" => Ok(AttributeName::Shadow),
             "corner_radius" => Ok(AttributeName::CornerRadius),
             "corner_smoothing" => Ok(AttributeName::CornerSmoothing),
@@ -97,6 +128,7 @@ impl FromStr for AttributeName {
             "cross_align" => Ok(AttributeName::CrossAlign),
             "text_align" => Ok(AttributeName::TextAlign),
             "text_shadow" => Ok(AttributeName::TextShadow),
+            "text_stroke" => Ok(AttributeName::TextStroke),
             "max_lines" => Ok(AttributeName::MaxLines),
             "line_height" => Ok(AttributeName::LineHeight),
             "letter_spacing" => Ok(AttributeName::LetterSpacing),
@@ -106,14 +138,23 @@ impl FromStr for AttributeName {
             "decoration_style" => Ok(AttributeName::DecorationStyle),
             "text_overflow" => Ok(AttributeName::TextOverflow),
             "rotate" => Ok(AttributeName::Rotate),
+            "scale" => Ok(AttributeName::Scale),
+            "translate" => Ok(AttributeName::Translate),
+            "skew" => Ok(AttributeName::Skew),
+            "transform_origin" => Ok(AttributeName::TransformOrigin),
             "overflow" => Ok(AttributeName::Overflow),
             "margin" => Ok(AttributeName::Margin),
+            "spacing" => Ok(AttributeName::Spacing),
+            "flex_shrink" => Ok(AttributeName::FlexShrink),
+            "aspect_ratio" => Ok(AttributeName::AspectRatio),
             "position" => Ok(AttributeName::Position),
             "position_top" => Ok(AttributeName::PositionTop),
             "position_right" => Ok(AttributeName::PositionRight),
             "position_bottom" => Ok(AttributeName::PositionBottom),
             "position_left" => Ok(AttributeName::PositionLeft),
             "opacity" => Ok(AttributeName::Opacity),
+            "blur" => Ok(AttributeName::Blur),
+            "backdrop_blur" => Ok(AttributeName::BackdropBlur),
             "content" => Ok(AttributeName::Content),
             "name" => Ok(AttributeName::Name),
             "focusable" => Ok(AttributeName::Focusable),
@@ -135,6 +176,9 @@ impl FromStr for AttributeName {
             "highlight_mode" => Ok(AttributeName::HighlightMode),
             "image_reference" => Ok(AttributeName::ImageReference),
             "image_data" => Ok(AttributeName::ImageData),
+            "image_fit" => Ok(AttributeName::ImageFit),
+            "image_align" => Ok(AttributeName::ImageAlign),
+            "sampling" => Ok(AttributeName::Sampling),
             "svg_data" => Ok(AttributeName::SvgData),
             "svg_content" => Ok(AttributeName::SvgContent),
             _ => Err(format!("{attr} not supported.")),
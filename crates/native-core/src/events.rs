@@ -9,12 +9,18 @@ pub enum EventName {
     RightClick,
 
     MouseDown,
+    MouseUp,
+    DoubleClick,
     MouseOver,
     MouseEnter,
     MouseLeave,
 
     Wheel,
 
+    Pinch,
+    Rotate,
+    Pan,
+
     PointerOver,
     PointerDown,
     PointerEnter,
@@ -37,6 +43,23 @@ pub enum EventName {
     GlobalFileHoverCancelled,
 
     FileDrop,
+
+    Focus,
+    Blur,
+    FocusIn,
+    FocusOut,
+
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
+
+    ClickCapture,
+    MouseDownCapture,
+    WheelCapture,
+    PointerDownCapture,
+    PointerUpCapture,
+    KeyDownCapture,
+    KeyUpCapture,
 }
 
 impl FromStr for EventName {
@@ -48,10 +71,15 @@ impl FromStr for EventName {
             "rightclick" => Ok(EventName::RightClick),
             "middleclick" => Ok(EventName::MiddleClick),
             "mousedown" => Ok(EventName::MouseDown),
+            "mouseup" => Ok(EventName::MouseUp),
+            "doubleclick" => Ok(EventName::DoubleClick),
             "mouseover" => Ok(EventName::MouseOver),
             "mouseenter" => Ok(EventName::MouseEnter),
             "mouseleave" => Ok(EventName::MouseLeave),
             "wheel" => Ok(EventName::Wheel),
+            "pinch" => Ok(EventName::Pinch),
+            "rotate" => Ok(EventName::Rotate),
+            "pan" => Ok(EventName::Pan),
             "pointerover" => Ok(EventName::PointerOver),
             "pointerdown" => Ok(EventName::PointerDown),
             "pointerenter" => Ok(EventName::PointerEnter),
@@ -70,6 +98,20 @@ impl FromStr for EventName {
             "filedrop" => Ok(EventName::FileDrop),
             "globalfilehover" => Ok(EventName::GlobalFileHover),
             "globalfilehovercancelled" => Ok(EventName::GlobalFileHoverCancelled),
+            "focus" => Ok(EventName::Focus),
+            "blur" => Ok(EventName::Blur),
+            "focusin" => Ok(EventName::FocusIn),
+            "focusout" => Ok(EventName::FocusOut),
+            "compositionstart" => Ok(EventName::CompositionStart),
+            "compositionupdate" => Ok(EventName::CompositionUpdate),
+            "compositionend" => Ok(EventName::CompositionEnd),
+            "clickcapture" => Ok(EventName::ClickCapture),
+            "mousedowncapture" => Ok(EventName::MouseDownCapture),
+            "wheelcapture" => Ok(EventName::WheelCapture),
+            "pointerdowncapture" => Ok(EventName::PointerDownCapture),
+            "pointerupcapture" => Ok(EventName::PointerUpCapture),
+            "keydowncapture" => Ok(EventName::KeyDownCapture),
+            "keyupcapture" => Ok(EventName::KeyUpCapture),
             _ => Err(()),
         }
     }
@@ -82,10 +124,15 @@ impl From<EventName> for &str {
             EventName::MiddleClick => "middleclick",
             EventName::RightClick => "rightclick",
             EventName::MouseDown => "mousedown",
+            EventName::MouseUp => "mouseup",
+            EventName::DoubleClick => "doubleclick",
             EventName::MouseOver => "mouseover",
             EventName::MouseEnter => "mouseenter",
             EventName::MouseLeave => "mouseleave",
             EventName::Wheel => "wheel",
+            EventName::Pinch => "pinch",
+            EventName::Rotate => "rotate",
+            EventName::Pan => "pan",
             EventName::PointerOver => "pointerover",
             EventName::PointerDown => "pointerdown",
             EventName::PointerEnter => "pointerenter",
@@ -104,6 +151,20 @@ impl From<EventName> for &str {
             EventName::FileDrop => "filedrop",
             EventName::GlobalFileHover => "globalfilehover",
             EventName::GlobalFileHoverCancelled => "globalfilehovercancelled",
+            EventName::Focus => "focus",
+            EventName::Blur => "blur",
+            EventName::FocusIn => "focusin",
+            EventName::FocusOut => "focusout",
+            EventName::CompositionStart => "compositionstart",
+            EventName::CompositionUpdate => "compositionupdate",
+            EventName::CompositionEnd => "compositionend",
+            EventName::ClickCapture => "clickcapture",
+            EventName::MouseDownCapture => "mousedowncapture",
+            EventName::WheelCapture => "wheelcapture",
+            EventName::PointerDownCapture => "pointerdowncapture",
+            EventName::PointerUpCapture => "pointerupcapture",
+            EventName::KeyDownCapture => "keydowncapture",
+            EventName::KeyUpCapture => "keyupcapture",
         }
     }
 }
@@ -118,21 +179,24 @@ impl PartialOrd for EventName {
 
 impl Ord for EventName {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority().cmp(&other.priority())
+    }
+}
+
+impl EventName {
+    /// Order in which the events are emitted, lower goes first.
+    fn priority(&self) -> u8 {
         match self {
             // Always prioritize leave events before anything else
-            Self::MouseLeave | Self::PointerLeave => {
-                if self == other {
-                    std::cmp::Ordering::Equal
-                } else {
-                    std::cmp::Ordering::Less
-                }
-            }
-            _ => std::cmp::Ordering::Greater,
+            Self::MouseLeave | Self::PointerLeave => 0,
+            // A node loses the focus before the next one gains it
+            Self::Blur | Self::FocusOut => 1,
+            // Capture-phase events go before the events they capture
+            _ if self.is_capture() => 2,
+            _ => 3,
         }
     }
-}
 
-impl EventName {
     /// Get the equivalent to a global event
     pub fn get_global_event(&self) -> Option<Self> {
         match self {
@@ -146,6 +210,34 @@ impl EventName {
         }
     }
 
+    /// Get the equivalent to a capture-phase event
+    pub fn get_capture_event(&self) -> Option<Self> {
+        match self {
+            Self::Click => Some(Self::ClickCapture),
+            Self::MouseDown => Some(Self::MouseDownCapture),
+            Self::Wheel => Some(Self::WheelCapture),
+            Self::PointerDown => Some(Self::PointerDownCapture),
+            Self::PointerUp => Some(Self::PointerUpCapture),
+            Self::KeyDown => Some(Self::KeyDownCapture),
+            Self::KeyUp => Some(Self::KeyUpCapture),
+            _ => None,
+        }
+    }
+
+    /// Check if it's one of the capture-phase variants
+    pub fn is_capture(&self) -> bool {
+        matches!(
+            self,
+            Self::ClickCapture
+                | Self::MouseDownCapture
+                | Self::WheelCapture
+                | Self::PointerDownCapture
+                | Self::PointerUpCapture
+                | Self::KeyDownCapture
+                | Self::KeyUpCapture
+        )
+    }
+
     /// Some events might cause other events, like for example:
     /// A `mouseover` might also trigger a `mouseenter`
     /// A `mousedown` or a `touchdown` might also trigger a `pointerdown`
@@ -167,6 +259,13 @@ impl EventName {
             _ => {}
         }
 
+        // Every event can also be captured by the ancestors of its target
+        let capture_events = events
+            .iter()
+            .filter_map(|event: &Self| event.get_capture_event())
+            .collect::<SmallVec<[Self; 4]>>();
+        events.extend(capture_events);
+
         events
     }
 
@@ -188,6 +287,22 @@ impl EventName {
         )
     }
 
+    /// Check if the event means that a Node gained or lost the focus
+    pub fn is_focus(&self) -> bool {
+        matches!(
+            &self,
+            Self::Focus | Self::Blur | Self::FocusIn | Self::FocusOut
+        )
+    }
+
+    /// Check if it's one of the IME composition variants
+    pub fn is_composition(&self) -> bool {
+        matches!(
+            &self,
+            Self::CompositionStart | Self::CompositionUpdate | Self::CompositionEnd
+        )
+    }
+
     /// Check if the event means the cursor was moved
     pub fn was_cursor_moved(&self) -> bool {
         matches!(
@@ -199,23 +314,34 @@ impl EventName {
     // Bubble all events except:
     // - Keyboard events
     // - Mouse movements events
+    // - Capture-phase events
+    // - `focus` and `blur`
+    // - IME composition events
     pub fn does_bubble(&self) -> bool {
-        !matches!(
-            self,
-            Self::KeyDown
-                | Self::KeyUp
-                | Self::MouseLeave
-                | Self::PointerLeave
-                | Self::MouseEnter
-                | Self::PointerEnter
-                | Self::MouseOver
-                | Self::PointerOver
-        )
+        !self.is_capture()
+            && !self.is_composition()
+            && !matches!(
+                self,
+                Self::KeyDown
+                    | Self::KeyUp
+                    | Self::MouseLeave
+                    | Self::PointerLeave
+                    | Self::MouseEnter
+                    | Self::PointerEnter
+                    | Self::MouseOver
+                    | Self::PointerOver
+                    | Self::Focus
+                    | Self::Blur
+            )
     }
 
     // Only let events that do not move the mouse, go through solid nodes
     pub fn does_go_through_solid(&self) -> bool {
-        matches!(self, Self::KeyDown | Self::KeyUp)
+        self.is_composition()
+            || matches!(
+                self,
+                Self::KeyDown | Self::KeyUp | Self::KeyDownCapture | Self::KeyUpCapture
+            )
     }
 
     // Check if this event can change the hover state of a Node.
@@ -12,11 +12,8 @@ use freya_core::{
         NativePlatformSender,
     },
 };
+use torin::geometry::Area;
 use winit::{
-    dpi::{
-        LogicalPosition,
-        LogicalSize,
-    },
     event::WindowEvent,
     event_loop::EventLoopProxy,
     window::Window,
@@ -46,12 +43,7 @@ impl AccessKitManager {
     }
 
     /// Focus a new accessibility node
-    pub fn focus_node(
-        &mut self,
-        id: AccessibilityId,
-        platform_sender: &NativePlatformSender,
-        window: &Window,
-    ) {
+    pub fn focus_node(&mut self, id: AccessibilityId, platform_sender: &NativePlatformSender) {
         let tree = self
             .accessibility_manager
             .lock()
@@ -63,15 +55,13 @@ impl AccessKitManager {
                 state.focused_id = tree.focus;
             });
 
-            // Update the IME Cursor area
-            self.update_ime_position(tree.focus, window);
-
             // Update the adapter
             self.accessibility_adapter.update_if_active(|| tree);
         }
     }
 
-    fn update_ime_position(&self, accessibility_id: AccessibilityId, window: &Window) {
+    /// Get the area of the given accessibility node, in physical pixels.
+    pub fn node_area(&self, accessibility_id: AccessibilityId) -> Option<Area> {
         let accessibility_manager = self.accessibility_manager.lock().unwrap();
         let node = accessibility_manager.nodes.iter().find_map(|(id, n)| {
             if *id == accessibility_id {
@@ -80,20 +70,11 @@ impl AccessKitManager {
                 None
             }
         });
-        if let Some(node) = node {
-            let node_bounds = node.bounds();
-            if let Some(node_bounds) = node_bounds {
-                return window.set_ime_cursor_area(
-                    LogicalPosition::new(node_bounds.min_x(), node_bounds.min_y()),
-                    LogicalSize::new(node_bounds.width(), node_bounds.height()),
-                );
-            }
-        }
-
-        window.set_ime_cursor_area(
-            window.inner_position().unwrap_or_default(),
-            LogicalSize::<u32>::default(),
-        );
+        let node_bounds = node?.bounds()?;
+        Some(Area::new(
+            (node_bounds.min_x() as f32, node_bounds.min_y() as f32).into(),
+            (node_bounds.width() as f32, node_bounds.height() as f32).into(),
+        ))
     }
 
     /// Process an accessibility event
@@ -121,7 +102,6 @@ impl AccessKitManager {
         &mut self,
         direction: AccessibilityFocusDirection,
         platform_sender: &NativePlatformSender,
-        window: &Window,
     ) {
         let tree = self
             .accessibility_manager
@@ -134,9 +114,6 @@ impl AccessKitManager {
             state.focused_id = tree.focus;
         });
 
-        // Update the IME Cursor area
-        self.update_ime_position(tree.focus, window);
-
         // Update the Adapter
         self.accessibility_adapter.update_if_active(|| tree);
     }
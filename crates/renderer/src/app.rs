@@ -6,6 +6,7 @@ use dioxus_core::{
 };
 use freya_common::{
     EventMessage,
+    NodeSnapshotRequest,
     TextGroupMeasurement,
 };
 use freya_core::prelude::*;
@@ -31,7 +32,10 @@ use torin::geometry::{
 };
 use tracing::info;
 use winit::{
-    dpi::PhysicalSize,
+    dpi::{
+        PhysicalPosition,
+        PhysicalSize,
+    },
     event_loop::EventLoopProxy,
     window::Window,
 };
@@ -60,11 +64,14 @@ pub struct Application {
     pub(crate) accessibility: AccessKitManager,
     pub(crate) font_collection: FontCollection,
     pub(crate) font_mgr: FontMgr,
+    pub(crate) images_cache: ImagesCache,
     pub(crate) ticker_sender: broadcast::Sender<()>,
     pub(crate) plugins: PluginsManager,
     pub(crate) measure_layout_on_next_render: bool,
     pub(crate) default_fonts: Vec<String>,
     pub(crate) queued_focus_node: Option<AccessibilityId>,
+    pub(crate) measure_ime_cursor_area_on_next_render: bool,
+    pub(crate) ime_cursor_area: Area,
 }
 
 impl Application {
@@ -121,11 +128,14 @@ impl Application {
             platform_receiver,
             font_collection,
             font_mgr,
+            images_cache: ImagesCache::default(),
             ticker_sender: broadcast::channel(5).0,
             plugins,
             measure_layout_on_next_render: false,
             default_fonts,
             queued_focus_node: None,
+            measure_ime_cursor_area_on_next_render: true,
+            ime_cursor_area: Area::default(),
         }
     }
 
@@ -215,6 +225,7 @@ impl Application {
         }
 
         if must_relayout || must_repaint {
+            self.measure_ime_cursor_area_on_next_render = true;
             window.request_redraw();
         }
     }
@@ -262,30 +273,115 @@ impl Application {
         self.vdom.replace_template(template);
     }
 
-    /// Render the App into the Window Canvas
-    pub fn render(&mut self, hovered_node: &HoveredNode, canvas: &Canvas, window: &Window) {
+    /// Render the App into the Window Canvas.
+    /// The app is rendered into the `dirty_surface`, which is kept across frames so only its damaged areas are repainted,
+    /// and then copied into the window `surface`.
+    pub fn render(
+        &mut self,
+        hovered_node: &HoveredNode,
+        background: Color,
+        surface: &mut Surface,
+        dirty_surface: &mut Surface,
+        window: &Window,
+    ) {
+        let scale_factor = window.scale_factor() as f32;
+
         self.plugins.send(PluginEvent::BeforeRender {
-            canvas,
+            canvas: surface.canvas(),
             font_collection: &self.font_collection,
             freya_dom: &self.sdom.get(),
         });
 
-        self.start_render(hovered_node, canvas, window.scale_factor() as f32);
+        let damage = {
+            let fdom = self.sdom.get();
+
+            // The wireframe of the hovered node is not tracked, so repaint everything when the devtools are used
+            if hovered_node.is_some() {
+                fdom.damage_tracker().mark_full();
+            }
+
+            process_damage(&fdom, scale_factor)
+        };
+
+        if !damage.is_none() {
+            let dirty_canvas = dirty_surface.canvas();
+            dirty_canvas.save();
+
+            if let Damage::Partial(areas) = &damage {
+                let mut damaged_path = Path::new();
+                for area in areas {
+                    damaged_path.add_rect(
+                        Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                        None,
+                    );
+                }
+                dirty_canvas.clip_path(&damaged_path, ClipOp::Intersect, false);
+            }
+
+            dirty_canvas.clear(background);
+            self.start_render(hovered_node, dirty_canvas, scale_factor, &damage);
+            dirty_canvas.restore();
+        }
+
+        // Copy the app pixel by pixel into the window
+        let canvas = surface.canvas();
+        canvas.save();
+        canvas.reset_matrix();
+        canvas.clear(Color::TRANSPARENT);
+        dirty_surface.draw(canvas, (0., 0.), SamplingOptions::default(), None);
+        canvas.restore();
 
         self.accessibility
             .render_accessibility(window.title().as_str());
 
+        self.plugins.send(PluginEvent::Damage(&damage));
+
         self.plugins.send(PluginEvent::AfterRender {
-            canvas,
+            canvas: surface.canvas(),
             font_collection: &self.font_collection,
             freya_dom: &self.sdom.get(),
         });
+
+        if self.measure_ime_cursor_area_on_next_render {
+            self.update_ime_cursor_area(window);
+            self.measure_ime_cursor_area_on_next_render = false;
+        }
+    }
+
+    /// Place the IME candidates window next to the cursor of the text being edited,
+    /// or next to the focused Node if no text is being edited.
+    fn update_ime_cursor_area(&mut self, window: &Window) {
+        let ime_cursor_area = self
+            .sdom
+            .get()
+            .measure_ime_cursor_area(
+                &self.font_collection,
+                &self.default_fonts,
+                window.scale_factor() as f32,
+            )
+            .or_else(|| {
+                let focused_id = self.platform_sender.borrow().focused_id;
+                self.accessibility.node_area(focused_id)
+            })
+            .unwrap_or_default();
+
+        if self.ime_cursor_area == ime_cursor_area {
+            return;
+        }
+
+        window.set_ime_cursor_area(
+            PhysicalPosition::new(ime_cursor_area.min_x(), ime_cursor_area.min_y()),
+            PhysicalSize::new(ime_cursor_area.width(), ime_cursor_area.height()),
+        );
+
+        self.ime_cursor_area = ime_cursor_area;
     }
 
     /// Resize the Window
     pub fn resize(&mut self, window: &Window) {
         self.measure_layout_on_next_render = true;
         self.sdom.get().layout().reset();
+        self.sdom.get().damage_tracker().mark_full();
         self.platform_sender.send_modify(|state| {
             state.information = PlatformInformation::from_winit(window);
         })
@@ -299,8 +395,10 @@ impl Application {
     }
 
     pub fn focus_node(&mut self, node_id: AccessibilityId, window: &Window) {
+        let previous_focused_id = self.platform_sender.borrow().focused_id;
         self.accessibility
-            .focus_node(node_id, &self.platform_sender, window)
+            .focus_node(node_id, &self.platform_sender);
+        self.notify_focus_change(previous_focused_id, window);
     }
 
     pub fn queue_focus_node(&mut self, node_id: AccessibilityId) {
@@ -308,8 +406,37 @@ impl Application {
     }
 
     pub fn focus_next_node(&mut self, direction: AccessibilityFocusDirection, window: &Window) {
+        let previous_focused_id = self.platform_sender.borrow().focused_id;
         self.accessibility
-            .focus_next_node(direction, &self.platform_sender, window)
+            .focus_next_node(direction, &self.platform_sender);
+        self.notify_focus_change(previous_focused_id, window);
+    }
+
+    /// Emit the focus events if the focused Node changed.
+    fn notify_focus_change(&mut self, previous_focused_id: AccessibilityId, window: &Window) {
+        let focused_id = self.platform_sender.borrow().focused_id;
+        let focus_events = PlatformEvent::focus_events(previous_focused_id, focused_id);
+        if !focus_events.is_empty() {
+            self.measure_ime_cursor_area_on_next_render = true;
+            window.request_redraw();
+            self.events.extend(focus_events);
+            self.process_events(window.scale_factor());
+        }
+    }
+
+    /// Render the requested Node into an image and send it back.
+    pub fn render_node_snapshot(&mut self, request: NodeSnapshotRequest, scale_factor: f32) {
+        let snapshot = render_node_snapshot(
+            &self.sdom.get(),
+            request.node_id,
+            &request.config,
+            &mut self.font_collection,
+            &self.font_mgr,
+            &mut self.images_cache,
+            &self.default_fonts,
+            scale_factor,
+        );
+        (request.callback)(snapshot);
     }
 
     /// Notify components subscribed to event loop ticks.
@@ -324,8 +451,21 @@ impl Application {
         })
     }
 
+    /// Repaint the elements whose content might have changed without any of their states changing.
+    pub fn damage_referenced_elements(&self) {
+        self.sdom.get().damage_referenced_elements();
+    }
+
+    /// Relayout the `auto` sized images whose referenced image changed its size.
+    pub fn check_image_references(&mut self) {
+        if self.sdom.get().invalidate_image_references() {
+            self.measure_layout_on_next_render = true;
+        }
+    }
+
     /// Measure the layout
     pub fn process_layout(&mut self, inner_size: PhysicalSize<u32>, scale_factor: f64) {
+        self.measure_ime_cursor_area_on_next_render = true;
         self.accessibility.clear_accessibility();
 
         {
@@ -362,35 +502,57 @@ impl Application {
     }
 
     /// Start rendering the RealDOM to Window
-    pub fn start_render(&mut self, hovered_node: &HoveredNode, canvas: &Canvas, scale_factor: f32) {
+    pub fn start_render(
+        &mut self,
+        hovered_node: &HoveredNode,
+        canvas: &Canvas,
+        scale_factor: f32,
+        damage: &Damage,
+    ) {
         let fdom = self.sdom.get();
 
         let matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
         let opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
+        let blurs: Vec<(f32, Vec<NodeId>)> = Vec::default();
 
         let mut skia_renderer = SkiaRenderer {
             canvas,
             font_collection: &mut self.font_collection,
             font_manager: &self.font_mgr,
+            images_cache: &mut self.images_cache,
             matrices,
             opacities,
+            blurs,
             default_fonts: &self.default_fonts,
             scale_factor,
         };
 
-        process_render(&fdom, |fdom, node_id, layout_node, layout| {
-            let render_wireframe = if let Some(hovered_node) = &hovered_node {
-                hovered_node
-                    .lock()
-                    .unwrap()
-                    .map(|id| id == *node_id)
-                    .unwrap_or_default()
-            } else {
-                false
-            };
-            if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
-                skia_renderer.render(layout_node, &dioxus_node, render_wireframe, layout);
-            }
-        });
+        process_render(
+            &fdom,
+            damage,
+            |fdom, node_id, layout_node, layout, is_damaged| {
+                let render_wireframe = if let Some(hovered_node) = &hovered_node {
+                    hovered_node
+                        .lock()
+                        .unwrap()
+                        .map(|id| id == *node_id)
+                        .unwrap_or_default()
+                } else {
+                    false
+                };
+                if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
+                    skia_renderer.render(
+                        layout_node,
+                        &dioxus_node,
+                        render_wireframe,
+                        layout,
+                        is_damaged,
+                    );
+                }
+            },
+        );
+
+        // Drop the decoded images of removed Nodes
+        self.images_cache.clean(fdom.rdom());
     }
 }
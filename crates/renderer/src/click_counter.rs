@@ -0,0 +1,45 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use torin::geometry::CursorPoint;
+use winit::event::MouseButton;
+
+/// Max time between two presses for them to be considered consecutive clicks.
+const MULTI_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Max distance (in logical pixels) the cursor can travel between two consecutive clicks.
+const MULTI_CLICK_DISTANCE: f64 = 4.0;
+
+/// Counts the consecutive presses of a mouse button, so double and triple clicks can be detected.
+#[derive(Default)]
+pub struct ClickCounter {
+    last_press: Option<(Instant, CursorPoint, MouseButton)>,
+    count: u32,
+}
+
+impl ClickCounter {
+    /// Register a press of the given button and get its click count.
+    pub fn press(&mut self, button: MouseButton, cursor: CursorPoint, scale_factor: f64) -> u32 {
+        let now = Instant::now();
+
+        let is_consecutive =
+            self.last_press
+                .is_some_and(|(last_time, last_cursor, last_button)| {
+                    last_button == button
+                        && now.duration_since(last_time) <= MULTI_CLICK_INTERVAL
+                        && cursor.distance_to(last_cursor) <= MULTI_CLICK_DISTANCE * scale_factor
+                });
+
+        self.count = if is_consecutive { self.count + 1 } else { 1 };
+        self.last_press = Some((now, cursor, button));
+
+        self.count
+    }
+
+    /// Click count of the last press.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
@@ -52,6 +52,9 @@ pub struct WindowConfig {
     pub on_exit: Option<WindowCallback>,
     /// Hook function called with the Window Attributes.
     pub window_attributes_hook: Option<WindowBuilderHook>,
+    /// Render with the CPU instead of OpenGL.
+    /// Software rendering is also used when the OpenGL context can't be created.
+    pub software_rendering: bool,
 }
 
 impl Default for WindowConfig {
@@ -71,6 +74,7 @@ impl Default for WindowConfig {
             on_setup: None,
             on_exit: None,
             window_attributes_hook: None,
+            software_rendering: false,
         }
     }
 }
@@ -174,6 +178,12 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Render with the CPU instead of OpenGL.
+    pub fn with_software_rendering(mut self, software_rendering: bool) -> Self {
+        self.window_config.software_rendering = software_rendering;
+        self
+    }
+
     /// Pass a custom value that your app will consume.
     pub fn with_state(mut self, state: T) -> Self {
         self.state = Some(state);
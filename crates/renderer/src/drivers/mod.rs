@@ -0,0 +1,65 @@
+mod opengl;
+mod software;
+
+use freya_engine::prelude::Surface;
+pub use opengl::*;
+pub use software::*;
+use tracing::warn;
+use winit::{
+    event_loop::ActiveEventLoop,
+    window::{
+        Window,
+        WindowAttributes,
+    },
+};
+
+use crate::config::WindowConfig;
+
+/// Graphics backend the app is rendered with.
+pub enum GraphicsDriver {
+    OpenGL(OpenGLDriver),
+    Software(SoftwareDriver),
+}
+
+impl GraphicsDriver {
+    /// Create the Window along with the graphics driver to render it.
+    /// Falls back to software rendering if the OpenGL context can't be created.
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        window_attributes: WindowAttributes,
+        window_config: &WindowConfig,
+    ) -> (Self, Window) {
+        if !window_config.software_rendering {
+            match OpenGLDriver::new(
+                event_loop,
+                window_attributes.clone(),
+                window_config.transparent,
+            ) {
+                Ok((driver, window)) => return (Self::OpenGL(driver), window),
+                Err(err) => {
+                    warn!("Could not create the OpenGL context, falling back to software rendering: {err}")
+                }
+            }
+        }
+
+        let (driver, window) = SoftwareDriver::new(event_loop, window_attributes)
+            .expect("Could not create window with software rendering");
+        (Self::Software(driver), window)
+    }
+
+    /// Create the surface for Skia to render in, with the current size of the Window.
+    pub fn create_surface(&mut self, window: &Window) -> Surface {
+        match self {
+            Self::OpenGL(driver) => driver.create_surface(window),
+            Self::Software(driver) => driver.create_surface(window),
+        }
+    }
+
+    /// Show what was rendered in the surface.
+    pub fn present(&mut self, surface: &mut Surface) {
+        match self {
+            Self::OpenGL(driver) => driver.present(),
+            Self::Software(driver) => driver.present(surface),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    error::Error,
+    ffi::CString,
+    num::NonZeroU32,
+};
+
+use freya_engine::prelude::*;
+use gl::{
+    types::*,
+    *,
+};
+use glutin::{
+    config::{
+        ConfigTemplateBuilder,
+        GlConfig,
+    },
+    context::{
+        ContextApi,
+        ContextAttributesBuilder,
+        GlProfile,
+        NotCurrentGlContext,
+        PossiblyCurrentContext,
+        PossiblyCurrentGlContext,
+    },
+    display::{
+        GetGlDisplay,
+        GlDisplay,
+    },
+    surface::{
+        GlSurface,
+        Surface as GlutinSurface,
+        SurfaceAttributesBuilder,
+        SwapInterval,
+        WindowSurface,
+    },
+};
+use glutin_winit::DisplayBuilder;
+use winit::{
+    event_loop::ActiveEventLoop,
+    raw_window_handle::HasWindowHandle,
+    window::{
+        Window,
+        WindowAttributes,
+    },
+};
+
+/// Renders with the GPU through an OpenGL context.
+pub struct OpenGLDriver {
+    gr_context: DirectContext,
+    gl_surface: GlutinSurface<WindowSurface>,
+    gl_context: PossiblyCurrentContext,
+    fb_info: FramebufferInfo,
+    num_samples: usize,
+    stencil_size: usize,
+}
+
+impl OpenGLDriver {
+    /// Create the Window along with its OpenGL context.
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        window_attributes: WindowAttributes,
+        transparent: bool,
+    ) -> Result<(Self, Window), Box<dyn Error>> {
+        let template = ConfigTemplateBuilder::new()
+            .with_alpha_size(8)
+            .with_transparency(transparent);
+
+        let display_builder = DisplayBuilder::new().with_window_attributes(Some(window_attributes));
+        let (window, gl_config) = display_builder.build(event_loop, template, |configs| {
+            configs
+                .reduce(|accum, config| {
+                    let transparency_check = config.supports_transparency().unwrap_or(false)
+                        & !accum.supports_transparency().unwrap_or(false);
+
+                    if transparency_check || config.num_samples() < accum.num_samples() {
+                        config
+                    } else {
+                        accum
+                    }
+                })
+                .unwrap()
+        })?;
+
+        let window = window.ok_or("Could not create window with OpenGL context")?;
+
+        let window_handle = window.window_handle()?;
+
+        let context_attributes = ContextAttributesBuilder::new()
+            .with_profile(GlProfile::Core)
+            .build(Some(window_handle.as_raw()));
+
+        let fallback_context_attributes = ContextAttributesBuilder::new()
+            .with_profile(GlProfile::Core)
+            .with_context_api(ContextApi::Gles(None))
+            .build(Some(window_handle.as_raw()));
+
+        let not_current_gl_context = unsafe {
+            gl_config
+                .display()
+                .create_context(&gl_config, &context_attributes)
+                .or_else(|_| {
+                    gl_config
+                        .display()
+                        .create_context(&gl_config, &fallback_context_attributes)
+                })?
+        };
+
+        let (width, height): (u32, u32) = window.inner_size().into();
+
+        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
+            window_handle.as_raw(),
+            NonZeroU32::new(width.max(1)).unwrap(),
+            NonZeroU32::new(height.max(1)).unwrap(),
+        );
+
+        let gl_surface = unsafe {
+            gl_config
+                .display()
+                .create_window_surface(&gl_config, &attrs)?
+        };
+
+        let gl_context = not_current_gl_context.make_current(&gl_surface)?;
+
+        // Try setting vsync.
+        gl_surface
+            .set_swap_interval(&gl_context, SwapInterval::Wait(NonZeroU32::new(1).unwrap()))
+            .ok();
+
+        load_with(|s| {
+            gl_config
+                .display()
+                .get_proc_address(CString::new(s).unwrap().as_c_str())
+        });
+        let interface = Interface::new_load_with(|name| {
+            if name == "eglGetCurrentDisplay" {
+                return std::ptr::null();
+            }
+            gl_config
+                .display()
+                .get_proc_address(CString::new(name).unwrap().as_c_str())
+        })
+        .ok_or("Could not create interface")?;
+
+        let gr_context =
+            direct_contexts::make_gl(interface, None).ok_or("Could not create direct context")?;
+
+        let fb_info = {
+            let mut fboid: GLint = 0;
+            unsafe { GetIntegerv(FRAMEBUFFER_BINDING, &mut fboid) };
+
+            FramebufferInfo {
+                fboid: fboid.try_into().unwrap(),
+                format: Format::RGBA8.into(),
+                ..Default::default()
+            }
+        };
+
+        let num_samples = gl_config.num_samples() as usize;
+        let stencil_size = gl_config.stencil_size() as usize;
+
+        Ok((
+            Self {
+                gr_context,
+                gl_surface,
+                gl_context,
+                fb_info,
+                num_samples,
+                stencil_size,
+            },
+            window,
+        ))
+    }
+
+    /// Create the surface for Skia to render in, with the current size of the Window.
+    pub fn create_surface(&mut self, window: &Window) -> Surface {
+        let size = window.inner_size();
+
+        self.gl_surface.resize(
+            &self.gl_context,
+            NonZeroU32::new(size.width.max(1)).unwrap(),
+            NonZeroU32::new(size.height.max(1)).unwrap(),
+        );
+
+        let size = (
+            size.width.try_into().expect("Could not convert width"),
+            size.height.try_into().expect("Could not convert height"),
+        );
+        let backend_render_target = backend_render_targets::make_gl(
+            size,
+            self.num_samples,
+            self.stencil_size,
+            self.fb_info,
+        );
+        wrap_backend_render_target(
+            &mut self.gr_context,
+            &backend_render_target,
+            SurfaceOrigin::BottomLeft,
+            ColorType::RGBA8888,
+            None,
+            None,
+        )
+        .expect("Could not create skia surface")
+    }
+
+    /// Show what was rendered in the surface.
+    pub fn present(&mut self) {
+        self.gr_context.flush_and_submit();
+        self.gl_surface.swap_buffers(&self.gl_context).unwrap();
+    }
+}
+
+impl Drop for OpenGLDriver {
+    fn drop(&mut self) {
+        if !self.gl_context.is_current() && self.gl_context.make_current(&self.gl_surface).is_err()
+        {
+            self.gr_context.abandon();
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    error::Error,
+    num::NonZeroU32,
+};
+
+use freya_engine::prelude::*;
+use winit::{
+    event_loop::ActiveEventLoop,
+    raw_window_handle::{
+        DisplayHandle,
+        HandleError,
+        HasDisplayHandle,
+        HasWindowHandle,
+        RawDisplayHandle,
+        RawWindowHandle,
+        WindowHandle,
+    },
+    window::{
+        Window,
+        WindowAttributes,
+    },
+};
+
+/// Raw handles of the Window, so softbuffer doesn't need to own it.
+#[derive(Clone, Copy)]
+struct RawWindow {
+    window_handle: RawWindowHandle,
+    display_handle: RawDisplayHandle,
+}
+
+// SAFETY: The Window outlives the softbuffer surface, because the driver is dropped before the Window.
+impl HasWindowHandle for RawWindow {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        Ok(unsafe { WindowHandle::borrow_raw(self.window_handle) })
+    }
+}
+
+impl HasDisplayHandle for RawWindow {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        Ok(unsafe { DisplayHandle::borrow_raw(self.display_handle) })
+    }
+}
+
+/// Renders with the CPU into a raster surface that is then copied into the Window.
+pub struct SoftwareDriver {
+    surface: softbuffer::Surface<RawWindow, RawWindow>,
+    /// Pixels of the last frame, reused across frames.
+    pixels: Vec<u8>,
+}
+
+impl SoftwareDriver {
+    /// Create the Window along with its software buffer.
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        window_attributes: WindowAttributes,
+    ) -> Result<(Self, Window), Box<dyn Error>> {
+        let window = event_loop.create_window(window_attributes)?;
+
+        let raw_window = RawWindow {
+            window_handle: window.window_handle()?.as_raw(),
+            display_handle: window.display_handle()?.as_raw(),
+        };
+
+        let context = softbuffer::Context::new(raw_window)?;
+        let surface = softbuffer::Surface::new(&context, raw_window)?;
+
+        Ok((
+            Self {
+                surface,
+                pixels: Vec::new(),
+            },
+            window,
+        ))
+    }
+
+    /// Create the surface for Skia to render in, with the current size of the Window.
+    pub fn create_surface(&mut self, window: &Window) -> Surface {
+        let size = window.inner_size();
+        let (width, height) = (size.width.max(1), size.height.max(1));
+
+        self.surface
+            .resize(
+                NonZeroU32::new(width).unwrap(),
+                NonZeroU32::new(height).unwrap(),
+            )
+            .ok();
+
+        raster_n32_premul((width as i32, height as i32)).expect("Could not create skia surface")
+    }
+
+    /// Copy what was rendered in the surface into the Window.
+    pub fn present(&mut self, surface: &mut Surface) {
+        let (width, height) = (surface.width(), surface.height());
+        let row_bytes = width as usize * 4;
+
+        self.pixels.resize(row_bytes * height as usize, 0);
+
+        let info = ImageInfo::new(
+            (width, height),
+            ColorType::BGRA8888,
+            AlphaType::Premul,
+            None,
+        );
+        if !surface.read_pixels(&info, &mut self.pixels, row_bytes, (0, 0)) {
+            return;
+        }
+
+        let Ok(mut buffer) = self.surface.buffer_mut() else {
+            return;
+        };
+
+        // softbuffer expects the pixels as `0RGB`
+        for (pixel, bgra) in buffer.iter_mut().zip(self.pixels.chunks_exact(4)) {
+            *pixel = u32::from_le_bytes([bgra[0], bgra[1], bgra[2], 0]);
+        }
+
+        buffer.present().ok();
+    }
+}
@@ -12,8 +12,10 @@ pub use renderer::DesktopRenderer;
 
 mod accessibility;
 mod app;
+mod click_counter;
 mod config;
 pub mod devtools;
+mod drivers;
 mod renderer;
 mod window_state;
 mod winit_waker;
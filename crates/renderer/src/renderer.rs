@@ -1,7 +1,4 @@
-use std::{
-    num::NonZeroU32,
-    path::PathBuf,
-};
+use std::path::PathBuf;
 
 use dioxus_core::VirtualDom;
 use freya_common::EventMessage;
@@ -20,10 +17,8 @@ use freya_elements::events::{
     map_winit_physical_key,
     Code,
     Key,
-};
-use glutin::prelude::{
-    GlSurface,
-    PossiblyCurrentGlContext,
+    TouchpadGesture,
+    WheelDeltaMode,
 };
 use torin::geometry::CursorPoint;
 use winit::{
@@ -47,9 +42,10 @@ use winit::{
 };
 
 use crate::{
+    click_counter::ClickCounter,
     devtools::Devtools,
     window_state::{
-        create_surface,
+        create_dirty_surface,
         CreatedState,
         NotCreatedState,
         WindowState,
@@ -66,6 +62,8 @@ pub struct DesktopRenderer<'a, State: Clone + 'static> {
     pub(crate) state: WindowState<'a, State>,
     pub(crate) hovered_node: HoveredNode,
     pub(crate) cursor_pos: CursorPoint,
+    pub(crate) click_counter: ClickCounter,
+    pub(crate) is_composing: bool,
     pub(crate) modifiers_state: ModifiersState,
     pub(crate) dropped_file_path: Option<PathBuf>,
 }
@@ -129,6 +127,8 @@ impl<'a, State: Clone + 'static> DesktopRenderer<'a, State> {
             hovered_node,
             event_loop_proxy: proxy,
             cursor_pos: CursorPoint::default(),
+            click_counter: ClickCounter::default(),
+            is_composing: false,
             modifiers_state: ModifiersState::default(),
             dropped_file_path: None,
         }
@@ -196,6 +196,7 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 app.focus_node(id, window);
             }
             EventMessage::RequestRerender => {
+                app.damage_referenced_elements();
                 window.request_redraw();
             }
             EventMessage::RemeasureTextGroup(text_id) => {
@@ -223,6 +224,9 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 app.queue_focus_node(node_id);
             }
             EventMessage::ExitApp => event_loop.exit(),
+            EventMessage::RenderNodeSnapshot(request) => {
+                app.render_node_snapshot(request, scale_factor as f32);
+            }
             ev => {
                 if let EventMessage::UpdateTemplate(template) = ev {
                     app.vdom_replace_template(template);
@@ -245,18 +249,13 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
     ) {
         let scale_factor = self.scale_factor();
         let CreatedState {
-            gr_context,
+            graphics_driver,
             surface,
-            gl_surface,
-            gl_context,
+            dirty_surface,
             window,
             app,
             window_config,
-            fb_info,
-            num_samples,
-            stencil_size,
             is_window_focused,
-            ..
         } = self.state.created_state();
         app.accessibility
             .process_accessibility_event(&event, window);
@@ -267,7 +266,52 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                 });
             }
             WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::Ime(Ime::Preedit(text, cursor)) => {
+                if !text.is_empty() {
+                    if !self.is_composing {
+                        self.is_composing = true;
+                        self.send_event(PlatformEvent::Composition {
+                            name: EventName::CompositionStart,
+                            text: text.clone(),
+                            cursor,
+                        });
+                    }
+
+                    self.send_event(PlatformEvent::Composition {
+                        name: EventName::CompositionUpdate,
+                        text,
+                        cursor,
+                    });
+                } else if self.is_composing {
+                    // An empty preedit means that the composition was either cancelled or is about to be committed
+                    self.is_composing = false;
+                    self.send_event(PlatformEvent::Composition {
+                        name: EventName::CompositionEnd,
+                        text,
+                        cursor: None,
+                    });
+                }
+            }
+            WindowEvent::Ime(Ime::Disabled) => {
+                if self.is_composing {
+                    self.is_composing = false;
+                    self.send_event(PlatformEvent::Composition {
+                        name: EventName::CompositionEnd,
+                        text: String::new(),
+                        cursor: None,
+                    });
+                }
+            }
             WindowEvent::Ime(Ime::Commit(text)) => {
+                if self.is_composing {
+                    self.is_composing = false;
+                    self.send_event(PlatformEvent::Composition {
+                        name: EventName::CompositionEnd,
+                        text: text.clone(),
+                        cursor: None,
+                    });
+                }
+
                 self.send_event(PlatformEvent::Keyboard {
                     name: EventName::KeyDown,
                     key: Key::Character(text),
@@ -282,56 +326,123 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     scale_factor_is_different
                 });
 
+                app.check_image_references();
+
                 if app.measure_layout_on_next_render {
                     app.process_layout(window.inner_size(), scale_factor);
                     app.process_accessibility(window);
 
                     app.measure_layout_on_next_render = false;
                 }
-                surface.canvas().clear(window_config.background);
-                app.render(&self.hovered_node, surface.canvas(), window);
+                app.render(
+                    &self.hovered_node,
+                    window_config.background,
+                    surface,
+                    dirty_surface,
+                    window,
+                );
                 app.event_loop_tick();
                 window.pre_present_notify();
-                gr_context.flush_and_submit();
-                gl_surface.swap_buffers(gl_context).unwrap();
+                graphics_driver.present(surface);
             }
             WindowEvent::MouseInput { state, button, .. } => {
                 app.set_navigation_mode(NavigationMode::NotKeyboard);
 
-                let name = match state {
-                    ElementState::Pressed => EventName::MouseDown,
-                    ElementState::Released => match button {
-                        MouseButton::Middle => EventName::MiddleClick,
-                        MouseButton::Right => EventName::RightClick,
-                        MouseButton::Left => EventName::Click,
-                        _ => EventName::PointerUp,
-                    },
+                let (name, click_count) = match state {
+                    ElementState::Pressed => {
+                        let click_count =
+                            self.click_counter
+                                .press(button, self.cursor_pos, scale_factor);
+                        (EventName::MouseDown, click_count)
+                    }
+                    ElementState::Released => {
+                        let click_count = self.click_counter.count();
+
+                        self.send_event(PlatformEvent::MouseClick {
+                            name: EventName::MouseUp,
+                            cursor: self.cursor_pos,
+                            button,
+                            click_count,
+                        });
+
+                        let name = match button {
+                            MouseButton::Middle => EventName::MiddleClick,
+                            MouseButton::Right => EventName::RightClick,
+                            MouseButton::Left => EventName::Click,
+                            _ => EventName::PointerUp,
+                        };
+                        (name, click_count)
+                    }
                 };
 
-                self.send_event(PlatformEvent::Mouse {
+                self.send_event(PlatformEvent::MouseClick {
                     name,
                     cursor: self.cursor_pos,
-                    button: Some(button),
+                    button,
+                    click_count,
                 });
+
+                if name == EventName::Click && click_count == 2 {
+                    self.send_event(PlatformEvent::MouseClick {
+                        name: EventName::DoubleClick,
+                        cursor: self.cursor_pos,
+                        button,
+                        click_count,
+                    });
+                }
             }
             WindowEvent::MouseWheel { delta, phase, .. } => {
-                if TouchPhase::Moved == phase {
-                    let scroll_data = {
-                        match delta {
-                            MouseScrollDelta::LineDelta(x, y) => (
+                let (scroll_data, delta_mode) = {
+                    match delta {
+                        MouseScrollDelta::LineDelta(x, y) => (
+                            (
                                 (x * WHEEL_SPEED_MODIFIER) as f64,
                                 (y * WHEEL_SPEED_MODIFIER) as f64,
                             ),
-                            MouseScrollDelta::PixelDelta(pos) => (pos.x, pos.y),
+                            WheelDeltaMode::Line,
+                        ),
+                        MouseScrollDelta::PixelDelta(pos) => {
+                            ((pos.x, pos.y), WheelDeltaMode::Pixel)
                         }
-                    };
+                    }
+                };
 
-                    self.send_event(PlatformEvent::Wheel {
-                        name: EventName::Wheel,
-                        scroll: CursorPoint::from(scroll_data),
-                        cursor: self.cursor_pos,
-                    });
-                }
+                self.send_event(PlatformEvent::Wheel {
+                    name: EventName::Wheel,
+                    scroll: CursorPoint::from(scroll_data),
+                    cursor: self.cursor_pos,
+                    delta_mode,
+                    phase,
+                });
+            }
+            WindowEvent::PinchGesture { delta, phase, .. } => {
+                self.send_event(PlatformEvent::Touchpad {
+                    name: EventName::Pinch,
+                    cursor: self.cursor_pos,
+                    gesture: TouchpadGesture::Pinch { delta },
+                    phase,
+                });
+            }
+            WindowEvent::RotationGesture { delta, phase, .. } => {
+                self.send_event(PlatformEvent::Touchpad {
+                    name: EventName::Rotate,
+                    cursor: self.cursor_pos,
+                    gesture: TouchpadGesture::Rotate {
+                        delta: delta as f64,
+                    },
+                    phase,
+                });
+            }
+            WindowEvent::PanGesture { delta, phase, .. } => {
+                self.send_event(PlatformEvent::Touchpad {
+                    name: EventName::Pan,
+                    cursor: self.cursor_pos,
+                    gesture: TouchpadGesture::Pan {
+                        delta_x: delta.x as f64,
+                        delta_y: delta.y as f64,
+                    },
+                    phase,
+                });
             }
             WindowEvent::ModifiersChanged(modifiers) => {
                 self.modifiers_state = modifiers.state();
@@ -411,15 +522,9 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
                     force,
                 });
             }
-            WindowEvent::Resized(size) => {
-                *surface =
-                    create_surface(window, *fb_info, gr_context, *num_samples, *stencil_size);
-
-                gl_surface.resize(
-                    gl_context,
-                    NonZeroU32::new(size.width.max(1)).unwrap(),
-                    NonZeroU32::new(size.height.max(1)).unwrap(),
-                );
+            WindowEvent::Resized(_) => {
+                *surface = graphics_driver.create_surface(window);
+                *dirty_surface = create_dirty_surface(window, surface);
 
                 window.request_redraw();
 
@@ -453,19 +558,3 @@ impl<'a, State: Clone> ApplicationHandler<EventMessage> for DesktopRenderer<'a,
         self.run_on_exit();
     }
 }
-
-impl<T: Clone> Drop for DesktopRenderer<'_, T> {
-    fn drop(&mut self) {
-        if let WindowState::Created(CreatedState {
-            gl_context,
-            gl_surface,
-            gr_context,
-            ..
-        }) = &mut self.state
-        {
-            if !gl_context.is_current() && gl_context.make_current(gl_surface).is_err() {
-                gr_context.abandon();
-            }
-        }
-    }
-}
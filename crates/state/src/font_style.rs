@@ -14,6 +14,7 @@ use freya_native_core::{
         NodeMaskBuilder,
         State,
     },
+    tags::TagName,
     NodeId,
     SendAnyMap,
 };
@@ -25,12 +26,16 @@ use crate::{
     ExtSplit,
     Parse,
     TextOverflow,
+    TextStroke,
 };
 
 #[derive(Debug, Clone, PartialEq, Component)]
 pub struct FontStyleState {
     pub color: Color,
+    /// Background of `text` spans, it's not inherited.
+    pub background: Option<Color>,
     pub text_shadows: Vec<TextShadow>,
+    pub text_stroke: Option<TextStroke>,
     pub font_family: Vec<String>,
     pub font_size: f32,
     pub font_slant: Slant,
@@ -80,7 +85,9 @@ impl Default for FontStyleState {
     fn default() -> Self {
         Self {
             color: Color::BLACK,
+            background: None,
             text_shadows: Vec::new(),
+            text_stroke: None,
             font_family: Vec::new(),
             font_size: 16.0,
             font_weight: Weight::NORMAL,
@@ -108,11 +115,13 @@ impl State<CustomAttributeValues> for FontStyleState {
 
     type NodeDependencies = ();
 
-    const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Color,
+            AttributeName::Background,
             AttributeName::TextAlign,
             AttributeName::TextShadow,
+            AttributeName::TextStroke,
             AttributeName::FontSize,
             AttributeName::FontFamily,
             AttributeName::LineHeight,
@@ -126,7 +135,8 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::DecorationColor,
             AttributeName::DecorationStyle,
             AttributeName::TextOverflow,
-        ]));
+        ]))
+        .with_tag();
 
     fn update<'a>(
         &mut self,
@@ -139,6 +149,7 @@ impl State<CustomAttributeValues> for FontStyleState {
         let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
 
         let mut font_style = parent.map(|(v,)| v.clone()).unwrap_or_default();
+        font_style.background = None;
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
@@ -150,6 +161,23 @@ impl State<CustomAttributeValues> for FontStyleState {
                             }
                         }
                     }
+                    AttributeName::Background => {
+                        // Other elements paint their own background
+                        if node_view.tag() == Some(&TagName::Text) {
+                            if let Some(value) = attr.value.as_text() {
+                                if let Ok(background) = Color::parse(value) {
+                                    font_style.background = Some(background);
+                                }
+                            }
+                        }
+                    }
+                    AttributeName::TextStroke => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(text_stroke) = TextStroke::parse(value) {
+                                font_style.text_stroke = Some(text_stroke);
+                            }
+                        }
+                    }
                     AttributeName::TextShadow => {
                         if let Some(value) = attr.value.as_text() {
                             font_style.text_shadows = value
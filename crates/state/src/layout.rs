@@ -21,6 +21,8 @@ use freya_native_core_macro::partial_derive_state;
 use torin::prelude::*;
 
 use crate::{
+    parse_aspect_ratio,
+    parse_grid_tracks,
     CustomAttributeValues,
     NodeReference,
     Parse,
@@ -34,15 +36,24 @@ pub struct LayoutState {
     pub minimum_height: Size,
     pub maximum_height: Size,
     pub maximum_width: Size,
+    pub aspect_ratio: Option<f32>,
     pub padding: Gaps,
     pub margin: Gaps,
+    pub spacing: Length,
     pub direction: DirectionMode,
+    pub wrap: WrapMode,
+    pub grid_columns: Vec<GridTrack>,
+    pub grid_rows: Vec<GridTrack>,
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
     pub offset_y: Length,
     pub offset_x: Length,
+    pub scrollable: bool,
     pub main_alignment: Alignment,
     pub cross_alignment: Alignment,
     pub position: Position,
     pub content: Content,
+    pub flex_shrink: f32,
     pub node_ref: Option<NodeReference>,
     pub node_id: NodeId,
 }
@@ -63,20 +74,28 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::MinHeight,
             AttributeName::MaxWidth,
             AttributeName::MaxHeight,
+            AttributeName::AspectRatio,
             AttributeName::Padding,
             AttributeName::Direction,
+            AttributeName::Wrap,
+            AttributeName::GridColumns,
+            AttributeName::GridRows,
+            AttributeName::GridColumn,
+            AttributeName::GridRow,
             AttributeName::OffsetX,
             AttributeName::OffsetY,
             AttributeName::MainAlign,
             AttributeName::CrossAlign,
             AttributeName::Reference,
             AttributeName::Margin,
+            AttributeName::Spacing,
             AttributeName::Position,
             AttributeName::PositionTop,
             AttributeName::PositionRight,
             AttributeName::PositionBottom,
             AttributeName::PositionLeft,
             AttributeName::Content,
+            AttributeName::FlexShrink,
         ]));
 
     fn update<'a>(
@@ -99,46 +118,53 @@ impl State<CustomAttributeValues> for LayoutState {
                 match attr.attribute {
                     AttributeName::Width => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(width) = Size::parse(value) {
+                            if let Some(width) = parse_size(&attr.attribute, value) {
                                 layout.width = width;
                             }
                         }
                     }
                     AttributeName::Height => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(height) = Size::parse(value) {
+                            if let Some(height) = parse_size(&attr.attribute, value) {
                                 layout.height = height;
                             }
                         }
                     }
                     AttributeName::MinHeight => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(min_height) = Size::parse(value) {
+                            if let Some(min_height) = parse_size(&attr.attribute, value) {
                                 layout.minimum_height = min_height;
                             }
                         }
                     }
                     AttributeName::MinWidth => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(min_width) = Size::parse(value) {
+                            if let Some(min_width) = parse_size(&attr.attribute, value) {
                                 layout.minimum_width = min_width;
                             }
                         }
                     }
                     AttributeName::MaxHeight => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(max_height) = Size::parse(value) {
+                            if let Some(max_height) = parse_size(&attr.attribute, value) {
                                 layout.maximum_height = max_height;
                             }
                         }
                     }
                     AttributeName::MaxWidth => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(max_width) = Size::parse(value) {
+                            if let Some(max_width) = parse_size(&attr.attribute, value) {
                                 layout.maximum_width = max_width;
                             }
                         }
                     }
+                    AttributeName::AspectRatio => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(aspect_ratio) = parse_aspect_ratio(value) {
+                                layout.aspect_ratio = Some(aspect_ratio);
+                            }
+                        }
+                    }
                     AttributeName::Padding => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(padding) = Gaps::parse(value) {
@@ -153,18 +179,62 @@ impl State<CustomAttributeValues> for LayoutState {
                             }
                         }
                     }
+                    AttributeName::Spacing => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(spacing) = value.parse::<f32>() {
+                                layout.spacing = Length::new(spacing);
+                            }
+                        }
+                    }
                     AttributeName::Direction => {
                         if let Some(value) = attr.value.as_text() {
                             layout.direction = match value {
                                 "horizontal" => DirectionMode::Horizontal,
+                                "grid" => DirectionMode::Grid,
                                 _ => DirectionMode::Vertical,
                             }
                         }
                     }
+                    AttributeName::Wrap => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(wrap) = WrapMode::parse(value) {
+                                layout.wrap = wrap;
+                            }
+                        }
+                    }
+                    AttributeName::GridColumns => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(tracks) = parse_grid_tracks(value) {
+                                layout.grid_columns = tracks;
+                            }
+                        }
+                    }
+                    AttributeName::GridRows => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(tracks) = parse_grid_tracks(value) {
+                                layout.grid_rows = tracks;
+                            }
+                        }
+                    }
+                    AttributeName::GridColumn => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(placement) = GridPlacement::parse(value) {
+                                layout.grid_column = placement;
+                            }
+                        }
+                    }
+                    AttributeName::GridRow => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(placement) = GridPlacement::parse(value) {
+                                layout.grid_row = placement;
+                            }
+                        }
+                    }
                     AttributeName::OffsetY => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(scroll) = value.parse::<f32>() {
                                 layout.offset_y = Length::new(scroll);
+                                layout.scrollable = true;
                             }
                         }
                     }
@@ -172,6 +242,7 @@ impl State<CustomAttributeValues> for LayoutState {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(scroll) = value.parse::<f32>() {
                                 layout.offset_x = Length::new(scroll);
+                                layout.scrollable = true;
                             }
                         }
                     }
@@ -194,6 +265,9 @@ impl State<CustomAttributeValues> for LayoutState {
                             if let Ok(position) = Position::parse(value) {
                                 if layout.position.is_empty() {
                                     layout.position = position;
+                                } else if position.is_sticky() {
+                                    // Keep the edges that were already set
+                                    layout.position.make_sticky();
                                 }
                             }
                         }
@@ -233,6 +307,13 @@ impl State<CustomAttributeValues> for LayoutState {
                             }
                         }
                     }
+                    AttributeName::FlexShrink => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(flex_shrink) = value.parse::<f32>() {
+                                layout.flex_shrink = flex_shrink;
+                            }
+                        }
+                    }
                     AttributeName::Reference => {
                         if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(
                             reference,
@@ -256,3 +337,14 @@ impl State<CustomAttributeValues> for LayoutState {
         changed
     }
 }
+
+/// Parse a size attribute, reporting invalid values instead of silently ignoring them.
+fn parse_size(attribute: &AttributeName, value: &str) -> Option<Size> {
+    match Size::parse(value) {
+        Ok(size) => Some(size),
+        Err(err) => {
+            tracing::warn!("Invalid value `{value}` for the {attribute:?} attribute: {err}");
+            None
+        }
+    }
+}
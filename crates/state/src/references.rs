@@ -1,3 +1,9 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_common::ReferencedElements;
 use freya_native_core::{
     attributes::AttributeName,
     exports::shipyard::Component,
@@ -9,9 +15,11 @@ use freya_native_core::{
         NodeMaskBuilder,
         State,
     },
+    NodeId,
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::torin::Torin;
 
 use crate::{
     CanvasReference,
@@ -46,8 +54,10 @@ impl State<CustomAttributeValues> for ReferencesState {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
+        let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
+        let referenced_elements = context.get::<ReferencedElements>().unwrap();
         let mut references = ReferencesState::default();
 
         if let Some(attributes) = node_view.attributes() {
@@ -74,7 +84,24 @@ impl State<CustomAttributeValues> for ReferencesState {
             }
         }
 
-        let changed = &references != self;
+        // References are always equal, so compare what they point to instead.
+        // `auto` sized images depend on the size of their image
+        let changed_image_ref = match (&references.image_ref, &self.image_ref) {
+            (Some(new_ref), Some(old_ref)) => !Arc::ptr_eq(&new_ref.0, &old_ref.0),
+            (new_ref, old_ref) => new_ref.is_some() != old_ref.is_some(),
+        };
+
+        if changed_image_ref {
+            torin_layout.lock().unwrap().invalidate(node_view.node_id());
+        }
+
+        if references.image_ref.is_some() || references.canvas_ref.is_some() {
+            referenced_elements.insert_element(node_view.node_id());
+        } else {
+            referenced_elements.remove_element(node_view.node_id());
+        }
+
+        let changed = changed_image_ref || &references != self;
 
         *self = references;
         changed
@@ -1,3 +1,8 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
 use freya_native_core::{
     attributes::AttributeName,
     exports::shipyard::Component,
@@ -9,33 +14,44 @@ use freya_native_core::{
         NodeMaskBuilder,
         State,
     },
+    NodeId,
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::torin::Torin;
 
 use crate::{
     parsing::ExtSplit,
     AttributesBytes,
     Border,
     BorderAlignment,
+    Borders,
     CornerRadius,
     CustomAttributeValues,
     Fill,
+    ImageAlign,
+    ImageFit,
     OverflowMode,
     Parse,
+    SamplingMode,
     Shadow,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Component)]
 pub struct StyleState {
     pub background: Fill,
-    pub border: Border,
+    pub border: Borders,
     pub shadows: Vec<Shadow>,
     pub corner_radius: CornerRadius,
     pub image_data: Option<AttributesBytes>,
+    pub image_fit: ImageFit,
+    pub image_align: ImageAlign,
+    pub sampling: SamplingMode,
     pub svg_data: Option<AttributesBytes>,
     pub overflow: OverflowMode,
     pub opacity: Option<f32>,
+    pub blur: Option<f32>,
+    pub backdrop_blur: Option<f32>,
 }
 
 #[partial_derive_state]
@@ -51,15 +67,24 @@ impl State<CustomAttributeValues> for StyleState {
             AttributeName::Background,
             AttributeName::Layer,
             AttributeName::Border,
+            AttributeName::BorderTop,
+            AttributeName::BorderRight,
+            AttributeName::BorderBottom,
+            AttributeName::BorderLeft,
             AttributeName::BorderAlign,
             AttributeName::Shadow,
             AttributeName::CornerRadius,
             AttributeName::CornerSmoothing,
             AttributeName::ImageData,
+            AttributeName::ImageFit,
+            AttributeName::ImageAlign,
+            AttributeName::Sampling,
             AttributeName::SvgData,
             AttributeName::SvgContent,
             AttributeName::Overflow,
             AttributeName::Opacity,
+            AttributeName::Blur,
+            AttributeName::BackdropBlur,
         ]));
 
     fn update<'a>(
@@ -68,8 +93,9 @@ impl State<CustomAttributeValues> for StyleState {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
+        let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
         let mut style = StyleState::default();
 
         if let Some(attributes) = node_view.attributes() {
@@ -84,8 +110,8 @@ impl State<CustomAttributeValues> for StyleState {
                     }
                     AttributeName::Border => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut border) = Border::parse(value) {
-                                border.alignment = style.border.alignment;
+                            if let Ok(mut border) = Borders::parse(value) {
+                                border.set_alignment(style.border.alignment());
 
                                 style.border = border;
                             }
@@ -94,7 +120,7 @@ impl State<CustomAttributeValues> for StyleState {
                     AttributeName::BorderAlign => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(alignment) = BorderAlignment::parse(value) {
-                                style.border.alignment = alignment;
+                                style.border.set_alignment(alignment);
                             }
                         }
                     }
@@ -131,6 +157,27 @@ impl State<CustomAttributeValues> for StyleState {
                             style.image_data = Some(bytes.clone());
                         }
                     }
+                    AttributeName::ImageFit => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(image_fit) = ImageFit::parse(value) {
+                                style.image_fit = image_fit;
+                            }
+                        }
+                    }
+                    AttributeName::ImageAlign => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(image_align) = ImageAlign::parse(value) {
+                                style.image_align = image_align;
+                            }
+                        }
+                    }
+                    AttributeName::Sampling => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(sampling) = SamplingMode::parse(value) {
+                                style.sampling = sampling;
+                            }
+                        }
+                    }
                     AttributeName::SvgData => {
                         if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
                             attr.value
@@ -157,11 +204,51 @@ impl State<CustomAttributeValues> for StyleState {
                             }
                         }
                     }
+                    AttributeName::Blur => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(blur) = value.parse::<f32>() {
+                                style.blur = Some(blur.max(0.0));
+                            }
+                        }
+                    }
+                    AttributeName::BackdropBlur => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(backdrop_blur) = value.parse::<f32>() {
+                                style.backdrop_blur = Some(backdrop_blur.max(0.0));
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
         }
 
+        // The sides are applied after the `border` shorthand so they always override it
+        if let Some(attributes) = node_view.attributes() {
+            let alignment = style.border.alignment();
+            for attr in attributes {
+                let side = match attr.attribute {
+                    AttributeName::BorderTop => &mut style.border.top,
+                    AttributeName::BorderRight => &mut style.border.right,
+                    AttributeName::BorderBottom => &mut style.border.bottom,
+                    AttributeName::BorderLeft => &mut style.border.left,
+                    _ => continue,
+                };
+
+                if let Some(value) = attr.value.as_text() {
+                    if let Ok(mut border) = Border::parse(value) {
+                        border.alignment = alignment;
+                        *side = border;
+                    }
+                }
+            }
+        }
+
+        // `auto` sized images depend on the size of their image
+        if style.image_data != self.image_data {
+            torin_layout.lock().unwrap().invalidate(node_view.node_id());
+        }
+
         let changed = &style != self;
 
         *self = style;
@@ -1,3 +1,4 @@
+use freya_engine::prelude::*;
 use freya_native_core::{
     exports::shipyard::Component,
     node_ref::NodeView,
@@ -11,12 +12,65 @@ use freya_native_core::{
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::geometry::Area;
 
-use crate::CustomAttributeValues;
+use crate::{
+    parse_transform_values,
+    CustomAttributeValues,
+    Parse,
+    TransformOrigin,
+};
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
 pub struct TransformState {
     pub rotate_degs: Option<f32>,
+    pub scale: Option<(f32, f32)>,
+    pub translate: Option<(f32, f32)>,
+    pub skew_degs: Option<(f32, f32)>,
+    pub transform_origin: TransformOrigin,
+}
+
+impl TransformState {
+    /// Whether the element is transformed at all
+    pub fn is_transformed(&self) -> bool {
+        self.rotate_degs.is_some()
+            || self.scale.is_some()
+            || self.translate.is_some()
+            || self.skew_degs.is_some()
+    }
+
+    /// Create the matrix that transforms the given area, if there is any transformation.
+    /// The transformations are applied around the transform origin in this order: translate, rotate, scale and skew.
+    pub fn matrix(&self, area: &Area, scale_factor: f32) -> Option<Matrix> {
+        if !self.is_transformed() {
+            return None;
+        }
+
+        let (origin_x, origin_y) = self.transform_origin.point(area, scale_factor);
+
+        let mut matrix = Matrix::new_identity();
+        matrix.pre_translate((origin_x, origin_y));
+
+        if let Some((x, y)) = self.translate {
+            matrix.pre_translate((x * scale_factor, y * scale_factor));
+        }
+
+        if let Some(rotate_degs) = self.rotate_degs {
+            matrix.pre_rotate(rotate_degs, None);
+        }
+
+        if let Some(scale) = self.scale {
+            matrix.pre_scale(scale, None);
+        }
+
+        if let Some((x, y)) = self.skew_degs {
+            matrix.pre_skew((x.to_radians().tan(), y.to_radians().tan()), None);
+        }
+
+        matrix.pre_translate((-origin_x, -origin_y));
+
+        Some(matrix)
+    }
 }
 
 #[partial_derive_state]
@@ -28,7 +82,13 @@ impl State<CustomAttributeValues> for TransformState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Rotate]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::Rotate,
+            AttributeName::Scale,
+            AttributeName::Translate,
+            AttributeName::Skew,
+            AttributeName::TransformOrigin,
+        ]));
 
     fn update<'a>(
         &mut self,
@@ -38,28 +98,55 @@ impl State<CustomAttributeValues> for TransformState {
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         _context: &SendAnyMap,
     ) -> bool {
-        let mut rotate_degs = None;
+        let mut transform = TransformState::default();
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                #[allow(clippy::single_match)]
                 match attr.attribute {
                     AttributeName::Rotate => {
                         if let Some(value) = attr.value.as_text() {
                             if value.ends_with("deg") {
                                 if let Ok(degs) = value.replacen("deg", "", 1).parse::<f32>() {
-                                    rotate_degs = Some(degs)
+                                    transform.rotate_degs = Some(degs)
                                 }
                             }
                         }
                     }
+                    AttributeName::Scale => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok((x, y)) = parse_transform_values(value, "") {
+                                transform.scale = Some((x, y.unwrap_or(x)))
+                            }
+                        }
+                    }
+                    AttributeName::Translate => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok((x, y)) = parse_transform_values(value, "") {
+                                transform.translate = Some((x, y.unwrap_or_default()))
+                            }
+                        }
+                    }
+                    AttributeName::Skew => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok((x, y)) = parse_transform_values(value, "deg") {
+                                transform.skew_degs = Some((x, y.unwrap_or_default()))
+                            }
+                        }
+                    }
+                    AttributeName::TransformOrigin => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(transform_origin) = TransformOrigin::parse(value) {
+                                transform.transform_origin = transform_origin
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
         }
 
-        let changed = rotate_degs != self.rotate_degs;
-        *self = Self { rotate_degs };
+        let changed = transform != *self;
+        *self = transform;
         changed
     }
 }
@@ -12,6 +12,9 @@ impl Parse for Alignment {
         Ok(match value {
             "center" => Alignment::Center,
             "end" => Alignment::End,
+            "space-between" => Alignment::SpaceBetween,
+            "space-around" => Alignment::SpaceAround,
+            "space-evenly" => Alignment::SpaceEvenly,
             _ => Alignment::Start,
         })
     }
@@ -0,0 +1,28 @@
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseAspectRatioError;
+
+/// Parse an aspect ratio, either as a number (e.g `1.5`) or as a fraction (e.g `16 / 9`).
+pub fn parse_aspect_ratio(value: &str) -> Result<f32, ParseAspectRatioError> {
+    let aspect_ratio = if let Some((width, height)) = value.split_once('/') {
+        let width = width
+            .trim()
+            .parse::<f32>()
+            .map_err(|_| ParseAspectRatioError)?;
+        let height = height
+            .trim()
+            .parse::<f32>()
+            .map_err(|_| ParseAspectRatioError)?;
+        width / height
+    } else {
+        value
+            .trim()
+            .parse::<f32>()
+            .map_err(|_| ParseAspectRatioError)?
+    };
+
+    if aspect_ratio.is_finite() && aspect_ratio > 0.0 {
+        Ok(aspect_ratio)
+    } else {
+        Err(ParseAspectRatioError)
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use torin::scaled::Scaled;
+use torin::{
+    gaps::Gaps,
+    scaled::Scaled,
+};
 
 use crate::{
     Fill,
@@ -12,6 +15,8 @@ pub enum BorderStyle {
     #[default]
     None,
     Solid,
+    Dashed,
+    Dotted,
 }
 
 #[derive(Default, Clone, Debug, PartialEq)]
@@ -60,6 +65,8 @@ impl fmt::Display for BorderStyle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match self {
             BorderStyle::Solid => "solid",
+            BorderStyle::Dashed => "dashed",
+            BorderStyle::Dotted => "dotted",
             BorderStyle::None => "none",
         })
     }
@@ -74,25 +81,140 @@ impl Parse for Border {
     fn parse(value: &str) -> Result<Self, Self::Err> {
         let mut border_values = value.split_ascii_whitespace();
 
+        let width = border_values
+            .next()
+            .ok_or(ParseBorderError)?
+            .parse::<f32>()
+            .map_err(|_| ParseBorderError)?;
+
+        let (style, fill) = parse_style_and_fill(border_values)?;
+
         Ok(Border {
-            width: border_values
-                .next()
-                .ok_or(ParseBorderError)?
-                .parse::<f32>()
-                .map_err(|_| ParseBorderError)?,
-            style: match border_values.next().ok_or(ParseBorderError)? {
-                "solid" => BorderStyle::Solid,
-                _ => BorderStyle::None,
-            },
-            fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                .map_err(|_| ParseBorderError)?,
+            width,
+            style,
+            fill,
             alignment: BorderAlignment::default(),
         })
     }
 }
 
+fn parse_style_and_fill<'a>(
+    mut border_values: impl Iterator<Item = &'a str>,
+) -> Result<(BorderStyle, Fill), ParseBorderError> {
+    let style = match border_values.next().ok_or(ParseBorderError)? {
+        "solid" => BorderStyle::Solid,
+        "dashed" => BorderStyle::Dashed,
+        "dotted" => BorderStyle::Dotted,
+        _ => BorderStyle::None,
+    };
+    let fill = Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
+        .map_err(|_| ParseBorderError)?;
+
+    Ok((style, fill))
+}
+
 impl Scaled for Border {
     fn scale(&mut self, scale_factor: f32) {
         self.width *= scale_factor;
     }
 }
+
+/// Borders of each side of an element.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Borders {
+    pub top: Border,
+    pub right: Border,
+    pub bottom: Border,
+    pub left: Border,
+}
+
+impl Borders {
+    /// Use the same border for every side
+    pub fn all(border: Border) -> Self {
+        Self {
+            top: border.clone(),
+            right: border.clone(),
+            bottom: border.clone(),
+            left: border,
+        }
+    }
+
+    /// Whether every side has the same border
+    pub fn is_uniform(&self) -> bool {
+        self.top == self.right && self.top == self.bottom && self.top == self.left
+    }
+
+    /// Whether any of the sides is visible
+    pub fn is_visible(&self) -> bool {
+        self.sides().iter().any(|side| side.is_visible())
+    }
+
+    pub fn sides(&self) -> [&Border; 4] {
+        [&self.top, &self.right, &self.bottom, &self.left]
+    }
+
+    pub fn alignment(&self) -> BorderAlignment {
+        self.top.alignment
+    }
+
+    pub fn set_alignment(&mut self, alignment: BorderAlignment) {
+        self.top.alignment = alignment;
+        self.right.alignment = alignment;
+        self.bottom.alignment = alignment;
+        self.left.alignment = alignment;
+    }
+}
+
+impl Border {
+    /// Whether this border would paint anything
+    pub fn is_visible(&self) -> bool {
+        self.width > 0.0 && self.style != BorderStyle::None
+    }
+}
+
+impl Parse for Borders {
+    type Err = ParseBorderError;
+
+    /// Parse the `border` syntax, which accepts from one to four widths like `padding` does:
+    /// `[width...] <solid | dashed | dotted | none> [fill]`
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut border_values = value.split_ascii_whitespace().peekable();
+
+        let mut widths = Vec::new();
+        while let Some(width) =
+            border_values.next_if(|value| widths.len() < 4 && value.parse::<f32>().is_ok())
+        {
+            widths.push(width);
+        }
+
+        if widths.is_empty() {
+            return Err(ParseBorderError);
+        }
+
+        let widths = Gaps::parse(&widths.join(" ")).map_err(|_| ParseBorderError)?;
+        let (style, fill) = parse_style_and_fill(border_values)?;
+
+        let side = |width: f32| Border {
+            width,
+            style,
+            fill: fill.clone(),
+            alignment: BorderAlignment::default(),
+        };
+
+        Ok(Borders {
+            top: side(widths.top()),
+            right: side(widths.right()),
+            bottom: side(widths.bottom()),
+            left: side(widths.left()),
+        })
+    }
+}
+
+impl Scaled for Borders {
+    fn scale(&mut self, scale_factor: f32) {
+        self.top.scale(scale_factor);
+        self.right.scale(scale_factor);
+        self.bottom.scale(scale_factor);
+        self.left.scale(scale_factor);
+    }
+}
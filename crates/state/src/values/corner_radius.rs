@@ -4,7 +4,13 @@ use std::{
 };
 
 use freya_engine::prelude::*;
-use torin::scaled::Scaled;
+use torin::{
+    geometry::{
+        Area,
+        Point2D,
+    },
+    scaled::Scaled,
+};
 
 use crate::Parse;
 
@@ -70,6 +76,67 @@ impl CornerRadius {
         self.fill_top(value);
     }
 
+    /// Create a rounded rect of the given area using these radius.
+    pub fn rounded_rect(&self, area: &Area) -> RRect {
+        RRect::new_rect_radii(
+            Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+            &[
+                (self.top_left, self.top_left).into(),
+                (self.top_right, self.top_right).into(),
+                (self.bottom_right, self.bottom_right).into(),
+                (self.bottom_left, self.bottom_left).into(),
+            ],
+        )
+    }
+
+    /// Create the path of a rounded rect, using the smoothed corners if there is any smoothing.
+    pub fn rounded_path(&self, rounded_rect: RRect) -> Path {
+        let mut path = Path::new();
+
+        if self.smoothing > 0.0 {
+            let rect = rounded_rect.rect();
+            path.add_path(
+                &self.smoothed_path(rounded_rect),
+                (rect.left, rect.top),
+                None,
+            );
+        } else {
+            path.add_rrect(rounded_rect, None);
+        }
+
+        path
+    }
+
+    /// Check whether a point is inside of the given area once its corners are rounded.
+    /// Smoothed corners are treated as circular ones.
+    pub fn contains(&self, area: &Area, point: Point2D) -> bool {
+        if !area.contains(point) {
+            return false;
+        }
+
+        let max_radius = area.width().min(area.height()) / 2.0;
+        let corners = [
+            (self.top_left, area.min_x(), area.min_y(), 1.0, 1.0),
+            (self.top_right, area.max_x(), area.min_y(), -1.0, 1.0),
+            (self.bottom_right, area.max_x(), area.max_y(), -1.0, -1.0),
+            (self.bottom_left, area.min_x(), area.max_y(), 1.0, -1.0),
+        ];
+
+        corners
+            .into_iter()
+            .all(|(radius, x, y, direction_x, direction_y)| {
+                let radius = radius.min(max_radius);
+                let center_x = x + radius * direction_x;
+                let center_y = y + radius * direction_y;
+
+                // Only the points between the corner and the center of its arc can be outside
+                let is_in_corner = (point.x - center_x) * direction_x < 0.0
+                    && (point.y - center_y) * direction_y < 0.0;
+
+                !is_in_corner || (point.x - center_x).hypot(point.y - center_y) <= radius
+            })
+    }
+
     // https://github.com/aloisdeniel/figma_squircle/blob/main/lib/src/path_smooth_corners.dart
     pub fn smoothed_path(&self, rect: RRect) -> Path {
         let mut path = Path::new();
@@ -141,3 +141,154 @@ impl fmt::Display for LinearGradient {
         )
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RadialGradient {
+    pub stops: Vec<GradientStop>,
+}
+
+impl RadialGradient {
+    pub fn into_shader(&self, bounds: Rect<f32, Measure>) -> Option<Shader> {
+        let colors: Vec<Color> = self.stops.iter().map(|stop| stop.color).collect();
+        let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
+
+        let center = bounds.center();
+
+        // The gradient ends at the farthest corner
+        let radius = (bounds.width() / 2.0).hypot(bounds.height() / 2.0);
+
+        Shader::radial_gradient(
+            Point::new(center.x, center.y),
+            radius,
+            GradientShaderColors::Colors(&colors[..]),
+            Some(&offsets[..]),
+            TileMode::Clamp,
+            None,
+            None,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRadialGradientError;
+
+impl Parse for RadialGradient {
+    type Err = ParseRadialGradientError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        if !value.starts_with("radial-gradient(") || !value.ends_with(')') {
+            return Err(ParseRadialGradientError);
+        }
+
+        let mut gradient = RadialGradient::default();
+        let mut value = value.replacen("radial-gradient(", "", 1);
+        value.remove(value.rfind(')').ok_or(ParseRadialGradientError)?);
+
+        for stop in value.split_excluding_group(',', '(', ')') {
+            gradient
+                .stops
+                .push(GradientStop::parse(stop).map_err(|_| ParseRadialGradientError)?);
+        }
+
+        Ok(gradient)
+    }
+}
+
+impl fmt::Display for RadialGradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "radial-gradient({})",
+            self.stops
+                .iter()
+                .map(|stop| stop.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConicGradient {
+    pub stops: Vec<GradientStop>,
+    pub angle: f32,
+}
+
+impl ConicGradient {
+    pub fn into_shader(&self, bounds: Rect<f32, Measure>) -> Option<Shader> {
+        let colors: Vec<Color> = self.stops.iter().map(|stop| stop.color).collect();
+        let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
+
+        let center = bounds.center();
+        let center = Point::new(center.x, center.y);
+
+        // Skia sweeps start from the right, but conic gradients start from the top
+        let mut matrix = Matrix::new_identity();
+        matrix.pre_rotate(self.angle.to_degrees() - 90.0, Some(center));
+
+        Shader::sweep_gradient(
+            center,
+            GradientShaderColors::Colors(&colors[..]),
+            Some(&offsets[..]),
+            TileMode::Clamp,
+            None,
+            None,
+            Some(&matrix),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseConicGradientError;
+
+impl Parse for ConicGradient {
+    type Err = ParseConicGradientError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        if !value.starts_with("conic-gradient(") || !value.ends_with(')') {
+            return Err(ParseConicGradientError);
+        }
+
+        let mut gradient = ConicGradient::default();
+        let mut value = value.replacen("conic-gradient(", "", 1);
+        value.remove(value.rfind(')').ok_or(ParseConicGradientError)?);
+
+        let mut split = value.split_excluding_group(',', '(', ')');
+
+        let angle_or_first_stop = split.next().ok_or(ParseConicGradientError)?.trim();
+
+        if let Some(angle) = angle_or_first_stop.strip_suffix("deg") {
+            gradient.angle = angle
+                .parse::<f32>()
+                .map_err(|_| ParseConicGradientError)?
+                .to_radians();
+        } else {
+            gradient.stops.push(
+                GradientStop::parse(angle_or_first_stop).map_err(|_| ParseConicGradientError)?,
+            );
+        }
+
+        for stop in split {
+            gradient
+                .stops
+                .push(GradientStop::parse(stop).map_err(|_| ParseConicGradientError)?);
+        }
+
+        Ok(gradient)
+    }
+}
+
+impl fmt::Display for ConicGradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "conic-gradient({}deg, {})",
+            self.angle.to_degrees(),
+            self.stops
+                .iter()
+                .map(|stop| stop.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
@@ -0,0 +1,71 @@
+use torin::{
+    geometry::Length,
+    grid::{
+        GridPlacement,
+        GridTrack,
+    },
+};
+
+use crate::Parse;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseGridError;
+
+impl Parse for GridTrack {
+    type Err = ParseGridError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        if value == "auto" {
+            Ok(GridTrack::Inner)
+        } else if let Some(weight) = value.strip_suffix("fr") {
+            Ok(GridTrack::Weight(
+                weight.parse::<f32>().map_err(|_| ParseGridError)?,
+            ))
+        } else if let Some(percentage) = value.strip_suffix('%') {
+            Ok(GridTrack::Percentage(Length::new(
+                percentage.parse::<f32>().map_err(|_| ParseGridError)?,
+            )))
+        } else {
+            Ok(GridTrack::Pixels(Length::new(
+                value.parse::<f32>().map_err(|_| ParseGridError)?,
+            )))
+        }
+    }
+}
+
+impl Parse for GridPlacement {
+    type Err = ParseGridError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut placement = GridPlacement::default();
+
+        let mut values = value.split_ascii_whitespace();
+
+        while let Some(value) = values.next() {
+            match value {
+                "auto" => placement.start = None,
+                "span" => {
+                    placement.span = values
+                        .next()
+                        .ok_or(ParseGridError)?
+                        .parse::<usize>()
+                        .map_err(|_| ParseGridError)?
+                        .max(1);
+                }
+                start => {
+                    placement.start = Some(start.parse::<usize>().map_err(|_| ParseGridError)?);
+                }
+            }
+        }
+
+        Ok(placement)
+    }
+}
+
+/// Parse a list of grid tracks separated by whitespaces, e.g `100 1fr 2fr auto 20%`
+pub fn parse_grid_tracks(value: &str) -> Result<Vec<GridTrack>, ParseGridError> {
+    value
+        .split_ascii_whitespace()
+        .map(GridTrack::parse)
+        .collect()
+}
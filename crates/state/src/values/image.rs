@@ -0,0 +1,175 @@
+use std::fmt;
+
+use torin::geometry::{
+    Area,
+    Point2D,
+    Size2D,
+};
+
+use crate::Parse;
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFit {
+    #[default]
+    Fill,
+    Contain,
+    Cover,
+    None,
+    ScaleDown,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImageFitError;
+
+impl Parse for ImageFit {
+    type Err = ParseImageFitError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "fill" => ImageFit::Fill,
+            "contain" => ImageFit::Contain,
+            "cover" => ImageFit::Cover,
+            "none" => ImageFit::None,
+            "scale-down" => ImageFit::ScaleDown,
+            _ => return Err(ParseImageFitError),
+        })
+    }
+}
+
+impl fmt::Display for ImageFit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ImageFit::Fill => "fill",
+            ImageFit::Contain => "contain",
+            ImageFit::Cover => "cover",
+            ImageFit::None => "none",
+            ImageFit::ScaleDown => "scale-down",
+        })
+    }
+}
+
+impl ImageFit {
+    /// Get the size an image of the given size should be drawn with inside the given area size.
+    pub fn fit(&self, image_size: Size2D, area_size: Size2D) -> Size2D {
+        if image_size.is_empty() {
+            return area_size;
+        }
+
+        let width_ratio = area_size.width / image_size.width;
+        let height_ratio = area_size.height / image_size.height;
+
+        match self {
+            ImageFit::Fill => area_size,
+            ImageFit::Contain => image_size * width_ratio.min(height_ratio),
+            ImageFit::Cover => image_size * width_ratio.max(height_ratio),
+            ImageFit::None => image_size,
+            ImageFit::ScaleDown => image_size * width_ratio.min(height_ratio).min(1.0),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImageAlign {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Default for ImageAlign {
+    fn default() -> Self {
+        Self { x: 0.5, y: 0.5 }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImageAlignError;
+
+fn parse_image_align_value(value: &str) -> Result<f32, ParseImageAlignError> {
+    match value {
+        "start" => Ok(0.0),
+        "center" => Ok(0.5),
+        "end" => Ok(1.0),
+        _ => Err(ParseImageAlignError),
+    }
+}
+
+impl Parse for ImageAlign {
+    type Err = ParseImageAlignError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut values = value.split_ascii_whitespace();
+
+        let x = parse_image_align_value(values.next().ok_or(ParseImageAlignError)?)?;
+        let y = match values.next() {
+            Some(y) => parse_image_align_value(y)?,
+            None => x,
+        };
+
+        if values.next().is_some() {
+            return Err(ParseImageAlignError);
+        }
+
+        Ok(Self { x, y })
+    }
+}
+
+impl fmt::Display for ImageAlign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = |v: f32| {
+            if v <= 0.0 {
+                "start"
+            } else if v >= 1.0 {
+                "end"
+            } else {
+                "center"
+            }
+        };
+        write!(f, "{} {}", value(self.x), value(self.y))
+    }
+}
+
+impl ImageAlign {
+    /// Get the area an image with the given size should be drawn in, aligned inside the given area.
+    pub fn align(&self, image_size: Size2D, area: &Area) -> Area {
+        let x = area.min_x() + (area.width() - image_size.width) * self.x;
+        let y = area.min_y() + (area.height() - image_size.height) * self.y;
+
+        Area::new(Point2D::new(x, y), image_size)
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SamplingMode {
+    Nearest,
+    #[default]
+    Linear,
+    Mipmap,
+    Cubic,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseSamplingModeError;
+
+impl Parse for SamplingMode {
+    type Err = ParseSamplingModeError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "nearest" => SamplingMode::Nearest,
+            "linear" => SamplingMode::Linear,
+            "mipmap" => SamplingMode::Mipmap,
+            "cubic" => SamplingMode::Cubic,
+            _ => return Err(ParseSamplingModeError),
+        })
+    }
+}
+
+impl fmt::Display for SamplingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SamplingMode::Nearest => "nearest",
+            SamplingMode::Linear => "linear",
+            SamplingMode::Mipmap => "mipmap",
+            SamplingMode::Cubic => "cubic",
+        })
+    }
+}
@@ -1,4 +1,5 @@
 mod alignment;
+mod aspect_ratio;
 mod border;
 mod color;
 mod content;
@@ -9,14 +10,20 @@ mod fill;
 mod font;
 mod gaps;
 mod gradient;
+mod grid;
 mod highlight;
+mod image;
 mod overflow;
 mod position;
 mod shadow;
 mod size;
 mod text_shadow;
+mod text_stroke;
+mod transform;
+mod wrap;
 
 pub use alignment::*;
+pub use aspect_ratio::*;
 pub use border::*;
 pub use color::*;
 pub use content::*;
@@ -27,9 +34,14 @@ pub use fill::*;
 pub use font::*;
 pub use gaps::*;
 pub use gradient::*;
+pub use grid::*;
 pub use highlight::*;
+pub use image::*;
 pub use overflow::*;
 pub use position::*;
 pub use shadow::*;
 pub use size::*;
 pub use text_shadow::*;
+pub use text_stroke::*;
+pub use transform::*;
+pub use wrap::*;
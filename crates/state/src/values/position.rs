@@ -11,6 +11,7 @@ impl Parse for Position {
     fn parse(value: &str) -> Result<Self, Self::Err> {
         Ok(match value {
             "absolute" => Position::new_absolute(),
+            "sticky" => Position::new_sticky(),
             _ => Position::Stacked,
         })
     }
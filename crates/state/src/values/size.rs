@@ -1,7 +1,11 @@
+use std::fmt;
+
 use torin::{
     geometry::Length,
     size::{
+        run_calculations,
         DynamicCalculation,
+        LexFunction,
         Size,
     },
 };
@@ -9,7 +13,32 @@ use torin::{
 use crate::Parse;
 
 #[derive(Debug, PartialEq, Eq)]
-pub struct ParseSizeError;
+pub struct ParseSizeError {
+    /// Why the value could not be parsed.
+    pub reason: &'static str,
+    /// Byte offset in the parsed value where the error was found.
+    pub position: usize,
+}
+
+impl ParseSizeError {
+    pub fn new(reason: &'static str, position: usize) -> Self {
+        Self { reason, position }
+    }
+}
+
+impl fmt::Display for ParseSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.reason, self.position)
+    }
+}
+
+impl std::error::Error for ParseSizeError {}
+
+fn parse_number(value: &str, position: usize) -> Result<f32, ParseSizeError> {
+    value
+        .parse::<f32>()
+        .map_err(|_| ParseSizeError::new("invalid number", position))
+}
 
 impl Parse for Size {
     type Err = ParseSizeError;
@@ -21,60 +50,91 @@ impl Parse for Size {
             Ok(Size::Fill)
         } else if value == "fill-min" {
             Ok(Size::FillMinimum)
+        } else if value.starts_with("flex") {
+            let inner = value
+                .strip_prefix("flex(")
+                .ok_or(ParseSizeError::new("expected `flex(`", 0))?
+                .strip_suffix(')')
+                .ok_or(ParseSizeError::new("expected `)`", value.len()))?;
+            Ok(Size::Flex(Length::new(parse_number(
+                inner.trim(),
+                "flex(".len(),
+            )?)))
         } else if value.contains("calc") {
             Ok(Size::DynamicCalculations(Box::new(parse_calc(value)?)))
         } else if value.contains('%') {
-            Ok(Size::Percentage(Length::new(
-                value
-                    .replace('%', "")
-                    .parse::<f32>()
-                    .map_err(|_| ParseSizeError)?,
-            )))
+            Ok(Size::Percentage(Length::new(parse_number(
+                &value.replace('%', ""),
+                0,
+            )?)))
         } else if value.contains('v') {
-            Ok(Size::RootPercentage(Length::new(
-                value
-                    .replace('v', "")
-                    .parse::<f32>()
-                    .map_err(|_| ParseSizeError)?,
-            )))
+            Ok(Size::RootPercentage(Length::new(parse_number(
+                &value.replace('v', ""),
+                0,
+            )?)))
         } else {
-            Ok(Size::Pixels(Length::new(
-                value.parse::<f32>().map_err(|_| ParseSizeError)?,
-            )))
+            Ok(Size::Pixels(Length::new(parse_number(value, 0)?)))
         }
     }
 }
 
-pub fn parse_calc(mut value: &str) -> Result<Vec<DynamicCalculation>, ParseSizeError> {
+pub fn parse_calc(value: &str) -> Result<Vec<DynamicCalculation>, ParseSizeError> {
     let mut calcs = Vec::new();
 
-    value = value
+    // Positions in errors are relative to the full `calc(...)` value
+    let offset = "calc(".len();
+    let inner = value
         .strip_prefix("calc(")
-        .ok_or(ParseSizeError)?
+        .ok_or(ParseSizeError::new("expected `calc(`", 0))?
         .strip_suffix(')')
-        .ok_or(ParseSizeError)?;
-
-    let values = value.split_whitespace();
-
-    for val in values {
-        if val.contains('%') {
-            calcs.push(DynamicCalculation::Percentage(
-                val.replace('%', "").parse().map_err(|_| ParseSizeError)?,
-            ));
-        } else if val == "+" {
-            calcs.push(DynamicCalculation::Add);
-        } else if val == "-" {
-            calcs.push(DynamicCalculation::Sub);
-        } else if val == "/" {
-            calcs.push(DynamicCalculation::Div);
-        } else if val == "*" {
-            calcs.push(DynamicCalculation::Mul);
-        } else {
-            calcs.push(DynamicCalculation::Pixels(
-                val.parse::<f32>().map_err(|_| ParseSizeError)?,
-            ));
+        .ok_or(ParseSizeError::new("expected `)`", value.len()))?;
+
+    let mut chars = inner.char_indices().peekable();
+
+    while let Some((start, ch)) = chars.next() {
+        match ch {
+            ch if ch.is_whitespace() => {}
+            '+' => calcs.push(DynamicCalculation::Add),
+            '-' => calcs.push(DynamicCalculation::Sub),
+            '*' => calcs.push(DynamicCalculation::Mul),
+            '/' => calcs.push(DynamicCalculation::Div),
+            '(' => calcs.push(DynamicCalculation::OpenParenthesis),
+            ')' => calcs.push(DynamicCalculation::ClosedParenthesis),
+            ',' => calcs.push(DynamicCalculation::FunctionSeparator),
+            ch if ch.is_ascii_alphabetic() => {
+                let mut end = start + ch.len_utf8();
+                while let Some((i, ch)) = chars.next_if(|(_, ch)| ch.is_ascii_alphabetic()) {
+                    end = i + ch.len_utf8();
+                }
+
+                calcs.push(DynamicCalculation::Function(match &inner[start..end] {
+                    "min" => LexFunction::Min,
+                    "max" => LexFunction::Max,
+                    "clamp" => LexFunction::Clamp,
+                    _ => return Err(ParseSizeError::new("unknown function", offset + start)),
+                }));
+            }
+            ch if ch.is_ascii_digit() || ch == '.' => {
+                let mut end = start + ch.len_utf8();
+                while let Some((i, ch)) = chars.next_if(|(_, ch)| ch.is_ascii_digit() || *ch == '.')
+                {
+                    end = i + ch.len_utf8();
+                }
+
+                let number = parse_number(&inner[start..end], offset + start)?;
+
+                calcs.push(match chars.next_if(|(_, ch)| *ch == '%' || *ch == 'v') {
+                    Some((_, '%')) => DynamicCalculation::Percentage(number),
+                    Some(_) => DynamicCalculation::RootPercentage(number),
+                    None => DynamicCalculation::Pixels(number),
+                });
+            }
+            _ => return Err(ParseSizeError::new("unexpected character", offset + start)),
         }
     }
 
+    // Make sure the calculations are a valid expression
+    run_calculations(&calcs, 0.0, 0.0).ok_or(ParseSizeError::new("invalid expression", offset))?;
+
     Ok(calcs)
 }
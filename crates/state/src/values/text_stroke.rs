@@ -0,0 +1,32 @@
+use freya_engine::prelude::*;
+
+use crate::Parse;
+
+/// Outline drawn around the glyphs of a text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextStroke {
+    pub width: f32,
+    pub color: Color,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTextStrokeError;
+
+impl Parse for TextStroke {
+    type Err = ParseTextStrokeError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut stroke_values = value.split_ascii_whitespace();
+
+        let width = stroke_values
+            .next()
+            .ok_or(ParseTextStrokeError)?
+            .parse::<f32>()
+            .map_err(|_| ParseTextStrokeError)?;
+
+        let color = Color::parse(stroke_values.collect::<Vec<&str>>().join(" ").as_str())
+            .map_err(|_| ParseTextStrokeError)?;
+
+        Ok(TextStroke { width, color })
+    }
+}
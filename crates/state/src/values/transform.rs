@@ -0,0 +1,120 @@
+use torin::geometry::Area;
+
+use crate::Parse;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTransformError;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransformOriginValue {
+    Percentage(f32),
+    Pixels(f32),
+}
+
+impl TransformOriginValue {
+    fn parse(value: &str, start: &str, end: &str) -> Result<Self, ParseTransformError> {
+        Ok(match value {
+            "center" => Self::Percentage(50.0),
+            value if value == start => Self::Percentage(0.0),
+            value if value == end => Self::Percentage(100.0),
+            value => {
+                if let Some(percentage) = value.strip_suffix('%') {
+                    Self::Percentage(percentage.parse().map_err(|_| ParseTransformError)?)
+                } else {
+                    Self::Pixels(value.parse().map_err(|_| ParseTransformError)?)
+                }
+            }
+        })
+    }
+
+    fn resolve(&self, start: f32, size: f32, scale_factor: f32) -> f32 {
+        match self {
+            Self::Percentage(percentage) => start + size / 100.0 * percentage,
+            Self::Pixels(pixels) => start + pixels * scale_factor,
+        }
+    }
+}
+
+/// Point of an element around which its transformations are applied
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformOrigin {
+    pub x: TransformOriginValue,
+    pub y: TransformOriginValue,
+}
+
+impl Default for TransformOrigin {
+    fn default() -> Self {
+        Self {
+            x: TransformOriginValue::Percentage(50.0),
+            y: TransformOriginValue::Percentage(50.0),
+        }
+    }
+}
+
+impl TransformOrigin {
+    /// Get the point of the given area from where the transformations are applied
+    pub fn point(&self, area: &Area, scale_factor: f32) -> (f32, f32) {
+        (
+            self.x.resolve(area.min_x(), area.width(), scale_factor),
+            self.y.resolve(area.min_y(), area.height(), scale_factor),
+        )
+    }
+}
+
+impl Parse for TransformOrigin {
+    type Err = ParseTransformError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut values = value.split_whitespace();
+        let first = values.next().ok_or(ParseTransformError)?;
+        let second = values.next();
+
+        if values.next().is_some() {
+            return Err(ParseTransformError);
+        }
+
+        let mut origin = TransformOrigin::default();
+
+        match (first, second) {
+            // Keywords of the vertical axis can be used first
+            ("top" | "bottom", second) => {
+                origin.y = TransformOriginValue::parse(first, "top", "bottom")?;
+                if let Some(second) = second {
+                    origin.x = TransformOriginValue::parse(second, "left", "right")?;
+                }
+            }
+            (first, Some(second)) => {
+                origin.x = TransformOriginValue::parse(first, "left", "right")?;
+                origin.y = TransformOriginValue::parse(second, "top", "bottom")?;
+            }
+            (first, None) => {
+                origin.x = TransformOriginValue::parse(first, "left", "right")?;
+            }
+        }
+
+        Ok(origin)
+    }
+}
+
+/// Parse one or two numbers separated by whitespace, each one optionally followed by the given unit (e.g `deg`)
+pub fn parse_transform_values(
+    value: &str,
+    unit: &str,
+) -> Result<(f32, Option<f32>), ParseTransformError> {
+    let mut values = value.split_whitespace().map(|value| {
+        value
+            .strip_suffix(unit)
+            .unwrap_or(value)
+            .parse::<f32>()
+            .map_err(|_| ParseTransformError)
+    });
+
+    let first = values.next().ok_or(ParseTransformError)??;
+    let second = values.next().transpose()?;
+
+    if values.next().is_some() {
+        return Err(ParseTransformError);
+    }
+
+    Ok((first, second))
+}
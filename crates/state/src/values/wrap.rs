@@ -0,0 +1,17 @@
+use torin::wrap::WrapMode;
+
+use crate::Parse;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseWrapModeError;
+
+impl Parse for WrapMode {
+    type Err = ParseWrapModeError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "wrap" => WrapMode::Wrap,
+            _ => WrapMode::NoWrap,
+        })
+    }
+}
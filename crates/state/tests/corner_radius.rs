@@ -1,5 +1,10 @@
 use freya_engine::prelude::*;
 use freya_node_state::CornerRadius;
+use torin::geometry::{
+    Area,
+    Point2D,
+    Size2D,
+};
 
 #[test]
 fn smooth_corner_radius() {
@@ -26,3 +31,30 @@ fn smooth_corner_radius() {
     assert!(path.is_valid());
     assert!(path.is_last_contour_closed());
 }
+
+#[test]
+fn rounded_area_contains() {
+    let corner_radius = CornerRadius {
+        top_left: 20.,
+        top_right: 0.,
+        bottom_left: 100.,
+        bottom_right: 20.,
+        smoothing: 0.,
+    };
+    let area = Area::new(Point2D::new(0., 0.), Size2D::new(100., 50.));
+
+    // Outside of the rounded corners
+    assert!(!corner_radius.contains(&area, Point2D::new(2., 2.)));
+    assert!(!corner_radius.contains(&area, Point2D::new(98., 48.)));
+    // The radius can't be bigger than half of the area
+    assert!(!corner_radius.contains(&area, Point2D::new(3., 47.)));
+    assert!(corner_radius.contains(&area, Point2D::new(25., 25.)));
+
+    // Inside of the rounded corners
+    assert!(corner_radius.contains(&area, Point2D::new(8., 8.)));
+    assert!(corner_radius.contains(&area, Point2D::new(99., 1.)));
+    assert!(corner_radius.contains(&area, Point2D::new(50., 25.)));
+
+    // Outside of the area
+    assert!(!corner_radius.contains(&area, Point2D::new(101., 25.)));
+}
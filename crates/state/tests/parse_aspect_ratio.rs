@@ -0,0 +1,22 @@
+use freya_node_state::{
+    parse_aspect_ratio,
+    ParseAspectRatioError,
+};
+
+#[test]
+fn parse_number_aspect_ratio() {
+    assert_eq!(parse_aspect_ratio("1.5"), Ok(1.5));
+}
+
+#[test]
+fn parse_fraction_aspect_ratio() {
+    assert_eq!(parse_aspect_ratio("16 / 9"), Ok(16.0 / 9.0));
+    assert_eq!(parse_aspect_ratio("4/3"), Ok(4.0 / 3.0));
+}
+
+#[test]
+fn parse_invalid_aspect_ratio() {
+    assert_eq!(parse_aspect_ratio("16 / 0"), Err(ParseAspectRatioError));
+    assert_eq!(parse_aspect_ratio("-1"), Err(ParseAspectRatioError));
+    assert_eq!(parse_aspect_ratio("wide"), Err(ParseAspectRatioError));
+}
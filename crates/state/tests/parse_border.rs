@@ -3,6 +3,7 @@ use freya_node_state::{
     Border,
     BorderAlignment,
     BorderStyle,
+    Borders,
     Fill,
     GradientStop,
     LinearGradient,
@@ -89,3 +90,58 @@ fn parse_border_style() {
     );
     assert!(invalid.is_err());
 }
+
+#[test]
+fn parse_dashed_and_dotted_border_styles() {
+    let dashed = Border::parse("2 dashed red");
+    let dotted = Border::parse("2 dotted red");
+
+    assert_eq!(dashed.map(|border| border.style), Ok(BorderStyle::Dashed));
+    assert_eq!(dotted.map(|border| border.style), Ok(BorderStyle::Dotted));
+}
+
+#[test]
+fn parse_borders() {
+    let uniform = Borders::parse("1 solid red");
+    let bottom_only = Borders::parse("0 0 1 0 solid red");
+    let vertical_and_horizontal = Borders::parse("1 2 dashed blue");
+
+    let side = |width: f32, style: BorderStyle, color: Color| Border {
+        width,
+        fill: Fill::Color(color),
+        style,
+        alignment: BorderAlignment::default(),
+    };
+
+    assert_eq!(
+        uniform,
+        Ok(Borders::all(side(1.0, BorderStyle::Solid, Color::RED)))
+    );
+    assert_eq!(
+        bottom_only,
+        Ok(Borders {
+            top: side(0.0, BorderStyle::Solid, Color::RED),
+            right: side(0.0, BorderStyle::Solid, Color::RED),
+            bottom: side(1.0, BorderStyle::Solid, Color::RED),
+            left: side(0.0, BorderStyle::Solid, Color::RED),
+        })
+    );
+    assert_eq!(
+        vertical_and_horizontal,
+        Ok(Borders {
+            top: side(1.0, BorderStyle::Dashed, Color::BLUE),
+            right: side(2.0, BorderStyle::Dashed, Color::BLUE),
+            bottom: side(1.0, BorderStyle::Dashed, Color::BLUE),
+            left: side(2.0, BorderStyle::Dashed, Color::BLUE),
+        })
+    );
+    assert!(uniform.unwrap().is_uniform());
+    assert!(!bottom_only.unwrap().is_uniform());
+}
+
+#[test]
+fn parse_invalid_borders() {
+    assert!(Borders::parse("solid red").is_err());
+    assert!(Borders::parse("1 2 3 4 5 solid red").is_err());
+    assert!(Borders::parse("1 solid").is_err());
+}
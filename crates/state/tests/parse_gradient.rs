@@ -1,8 +1,11 @@
 use freya_engine::prelude::*;
 use freya_node_state::{
+    ConicGradient,
+    Fill,
     GradientStop,
     LinearGradient,
     Parse,
+    RadialGradient,
 };
 
 #[test]
@@ -92,3 +95,66 @@ fn invalid_gradients() {
     assert!(missing_color.is_err());
     assert!(missing_offset.is_err());
 }
+
+#[test]
+fn parse_radial_gradient() {
+    assert_eq!(
+        RadialGradient::parse("radial-gradient(red 0%, blue 100%)"),
+        Ok(RadialGradient {
+            stops: vec![
+                GradientStop {
+                    color: Color::RED,
+                    offset: 0.0,
+                },
+                GradientStop {
+                    color: Color::BLUE,
+                    offset: 1.0,
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn parse_conic_gradient() {
+    assert_eq!(
+        ConicGradient::parse("conic-gradient(90deg, red 0%, blue 100%)"),
+        Ok(ConicGradient {
+            angle: 90f32.to_radians(),
+            stops: vec![
+                GradientStop {
+                    color: Color::RED,
+                    offset: 0.0,
+                },
+                GradientStop {
+                    color: Color::BLUE,
+                    offset: 1.0,
+                }
+            ]
+        })
+    );
+    assert_eq!(
+        ConicGradient::parse("conic-gradient(red 0%, blue 100%)").map(|gradient| gradient.angle),
+        Ok(0.0)
+    );
+}
+
+#[test]
+fn parse_gradient_fills() {
+    assert!(matches!(
+        Fill::parse("radial-gradient(red 0%, blue 100%)"),
+        Ok(Fill::RadialGradient(_))
+    ));
+    assert!(matches!(
+        Fill::parse("conic-gradient(45deg, red 0%, blue 100%)"),
+        Ok(Fill::ConicGradient(_))
+    ));
+}
+
+#[test]
+fn invalid_radial_and_conic_gradients() {
+    assert!(RadialGradient::parse("radial-gradient(45deg, red 0%, blue 100%)").is_err());
+    assert!(RadialGradient::parse("radial-gradient(red 0%, blue 100%,)").is_err());
+    assert!(ConicGradient::parse("conic-gradient(abcdeg, red 0%, blue 100%)").is_err());
+    assert!(ConicGradient::parse("conic-gradient(red 0% blue 100%)").is_err());
+}
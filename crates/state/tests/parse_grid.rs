@@ -0,0 +1,61 @@
+use freya_node_state::{
+    parse_grid_tracks,
+    Parse,
+};
+use torin::{
+    geometry::Length,
+    grid::{
+        GridPlacement,
+        GridTrack,
+    },
+};
+
+#[test]
+fn parse_grid_track() {
+    assert_eq!(GridTrack::parse("auto"), Ok(GridTrack::Inner));
+    assert_eq!(
+        GridTrack::parse("100"),
+        Ok(GridTrack::Pixels(Length::new(100.0)))
+    );
+    assert_eq!(
+        GridTrack::parse("25%"),
+        Ok(GridTrack::Percentage(Length::new(25.0)))
+    );
+    assert_eq!(GridTrack::parse("2fr"), Ok(GridTrack::Weight(2.0)));
+}
+
+#[test]
+fn parse_grid_track_list() {
+    let tracks = parse_grid_tracks("100 1fr auto 20%");
+    assert_eq!(
+        tracks,
+        Ok(vec![
+            GridTrack::Pixels(Length::new(100.0)),
+            GridTrack::Weight(1.0),
+            GridTrack::Inner,
+            GridTrack::Percentage(Length::new(20.0)),
+        ])
+    );
+}
+
+#[test]
+fn parse_invalid_grid_track() {
+    let tracks = parse_grid_tracks("100 1xx");
+    assert!(tracks.is_err());
+}
+
+#[test]
+fn parse_grid_placement() {
+    assert_eq!(GridPlacement::parse("2"), Ok(GridPlacement::new(2, 1)));
+    assert_eq!(
+        GridPlacement::parse("1 span 3"),
+        Ok(GridPlacement::new(1, 3))
+    );
+    assert_eq!(
+        GridPlacement::parse("span 2"),
+        Ok(GridPlacement {
+            start: None,
+            span: 2
+        })
+    );
+}
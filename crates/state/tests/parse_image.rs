@@ -0,0 +1,82 @@
+use freya_node_state::{
+    ImageAlign,
+    ImageFit,
+    Parse,
+    SamplingMode,
+};
+use torin::geometry::{
+    Area,
+    Point2D,
+    Size2D,
+};
+
+#[test]
+fn parse_image_fit() {
+    assert_eq!(ImageFit::parse("fill"), Ok(ImageFit::Fill));
+    assert_eq!(ImageFit::parse("contain"), Ok(ImageFit::Contain));
+    assert_eq!(ImageFit::parse("cover"), Ok(ImageFit::Cover));
+    assert_eq!(ImageFit::parse("none"), Ok(ImageFit::None));
+    assert_eq!(ImageFit::parse("scale-down"), Ok(ImageFit::ScaleDown));
+    assert!(ImageFit::parse("stretch").is_err());
+}
+
+#[test]
+fn fit_image() {
+    let image = Size2D::new(200.0, 100.0);
+    let area = Size2D::new(100.0, 100.0);
+
+    assert_eq!(ImageFit::Fill.fit(image, area), Size2D::new(100.0, 100.0));
+    assert_eq!(ImageFit::Contain.fit(image, area), Size2D::new(100.0, 50.0));
+    assert_eq!(ImageFit::Cover.fit(image, area), Size2D::new(200.0, 100.0));
+    assert_eq!(ImageFit::None.fit(image, area), Size2D::new(200.0, 100.0));
+    assert_eq!(
+        ImageFit::ScaleDown.fit(image, area),
+        Size2D::new(100.0, 50.0)
+    );
+
+    // Small images are not scaled up by `scale-down`
+    let image = Size2D::new(20.0, 10.0);
+    assert_eq!(
+        ImageFit::ScaleDown.fit(image, area),
+        Size2D::new(20.0, 10.0)
+    );
+    assert_eq!(ImageFit::Contain.fit(image, area), Size2D::new(100.0, 50.0));
+}
+
+#[test]
+fn parse_image_align() {
+    assert_eq!(
+        ImageAlign::parse("center"),
+        Ok(ImageAlign { x: 0.5, y: 0.5 })
+    );
+    assert_eq!(
+        ImageAlign::parse("start end"),
+        Ok(ImageAlign { x: 0.0, y: 1.0 })
+    );
+    assert!(ImageAlign::parse("start end center").is_err());
+    assert!(ImageAlign::parse("left").is_err());
+}
+
+#[test]
+fn align_image() {
+    let area = Area::new(Point2D::new(10.0, 10.0), Size2D::new(100.0, 100.0));
+    let image = Size2D::new(100.0, 50.0);
+
+    assert_eq!(
+        ImageAlign::default().align(image, &area),
+        Area::new(Point2D::new(10.0, 35.0), image)
+    );
+    assert_eq!(
+        ImageAlign { x: 0.0, y: 1.0 }.align(image, &area),
+        Area::new(Point2D::new(10.0, 60.0), image)
+    );
+}
+
+#[test]
+fn parse_sampling() {
+    assert_eq!(SamplingMode::parse("nearest"), Ok(SamplingMode::Nearest));
+    assert_eq!(SamplingMode::parse("linear"), Ok(SamplingMode::Linear));
+    assert_eq!(SamplingMode::parse("mipmap"), Ok(SamplingMode::Mipmap));
+    assert_eq!(SamplingMode::parse("cubic"), Ok(SamplingMode::Cubic));
+    assert!(SamplingMode::parse("bilinear").is_err());
+}
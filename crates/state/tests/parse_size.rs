@@ -1,8 +1,12 @@
-use freya_node_state::Parse;
+use freya_node_state::{
+    Parse,
+    ParseSizeError,
+};
 use torin::{
     geometry::Length,
     size::{
         DynamicCalculation,
+        LexFunction,
         Size,
     },
 };
@@ -39,3 +43,75 @@ fn parse_calc_size() {
         ])))
     );
 }
+
+#[test]
+fn parse_flex_size() {
+    let size = Size::parse("flex(2.5)");
+    assert_eq!(size, Ok(Size::Flex(Length::new(2.5))));
+}
+
+#[test]
+fn parse_invalid_flex_size() {
+    let size = Size::parse("flex(2");
+    assert_eq!(size, Err(ParseSizeError::new("expected `)`", 6)));
+}
+
+#[test]
+fn parse_calc_size_with_parentheses_and_functions() {
+    let size = Size::parse("calc(min(50%, 200) + (10v-5)*2)");
+    assert_eq!(
+        size,
+        Ok(Size::DynamicCalculations(Box::new(vec![
+            DynamicCalculation::Function(LexFunction::Min),
+            DynamicCalculation::OpenParenthesis,
+            DynamicCalculation::Percentage(50.0),
+            DynamicCalculation::FunctionSeparator,
+            DynamicCalculation::Pixels(200.0),
+            DynamicCalculation::ClosedParenthesis,
+            DynamicCalculation::Add,
+            DynamicCalculation::OpenParenthesis,
+            DynamicCalculation::RootPercentage(10.0),
+            DynamicCalculation::Sub,
+            DynamicCalculation::Pixels(5.0),
+            DynamicCalculation::ClosedParenthesis,
+            DynamicCalculation::Mul,
+            DynamicCalculation::Pixels(2.0),
+        ])))
+    );
+}
+
+#[test]
+fn parse_invalid_calc_size() {
+    for value in [
+        "calc(100% -)",
+        "calc((100% - 5)",
+        "calc(100% - 5))",
+        "calc(50 50)",
+        "calc(clamp(10, 50%))",
+        "calc(average(10, 50%))",
+        "calc(10 $ 5)",
+        "calc()",
+    ] {
+        assert!(Size::parse(value).is_err(), "{value}");
+    }
+}
+
+#[test]
+fn parse_invalid_calc_size_error() {
+    assert_eq!(
+        Size::parse("calc(10 $ 5)"),
+        Err(ParseSizeError::new("unexpected character", 8))
+    );
+    assert_eq!(
+        Size::parse("calc(average(10, 50%))"),
+        Err(ParseSizeError::new("unknown function", 5))
+    );
+    assert_eq!(
+        Size::parse("calc(100% -)"),
+        Err(ParseSizeError::new("invalid expression", 5))
+    );
+    assert_eq!(
+        Size::parse("calc(10 $ 5)").unwrap_err().to_string(),
+        "unexpected character at position 8"
+    );
+}
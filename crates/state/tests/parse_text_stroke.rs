@@ -0,0 +1,23 @@
+use freya_engine::prelude::*;
+use freya_node_state::{
+    Parse,
+    TextStroke,
+};
+
+#[test]
+fn parse_text_stroke() {
+    let text_stroke = TextStroke::parse("2 rgb(255, 0, 0)");
+    assert_eq!(
+        text_stroke,
+        Ok(TextStroke {
+            width: 2.0,
+            color: Color::RED,
+        })
+    );
+}
+
+#[test]
+fn parse_text_stroke_without_color() {
+    assert!(TextStroke::parse("2").is_err());
+    assert!(TextStroke::parse("red").is_err());
+}
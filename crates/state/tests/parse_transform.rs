@@ -0,0 +1,86 @@
+use freya_node_state::{
+    parse_transform_values,
+    Parse,
+    ParseTransformError,
+    TransformOrigin,
+    TransformOriginValue,
+};
+
+#[test]
+fn parse_transform_single_value() {
+    assert_eq!(parse_transform_values("1.5", ""), Ok((1.5, None)));
+}
+
+#[test]
+fn parse_transform_two_values() {
+    assert_eq!(
+        parse_transform_values("10 -20", ""),
+        Ok((10.0, Some(-20.0)))
+    );
+    assert_eq!(
+        parse_transform_values("15deg 5deg", "deg"),
+        Ok((15.0, Some(5.0)))
+    );
+}
+
+#[test]
+fn parse_invalid_transform_values() {
+    assert_eq!(parse_transform_values("", ""), Err(ParseTransformError));
+    assert_eq!(
+        parse_transform_values("1 2 3", ""),
+        Err(ParseTransformError)
+    );
+    assert_eq!(parse_transform_values("big", ""), Err(ParseTransformError));
+}
+
+#[test]
+fn parse_transform_origin_keywords() {
+    assert_eq!(
+        TransformOrigin::parse("left top"),
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Percentage(0.0),
+            y: TransformOriginValue::Percentage(0.0),
+        })
+    );
+    assert_eq!(
+        TransformOrigin::parse("bottom right"),
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Percentage(100.0),
+            y: TransformOriginValue::Percentage(100.0),
+        })
+    );
+    assert_eq!(
+        TransformOrigin::parse("top"),
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Percentage(50.0),
+            y: TransformOriginValue::Percentage(0.0),
+        })
+    );
+}
+
+#[test]
+fn parse_transform_origin_values() {
+    assert_eq!(
+        TransformOrigin::parse("25% 10"),
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Percentage(25.0),
+            y: TransformOriginValue::Pixels(10.0),
+        })
+    );
+    assert_eq!(
+        TransformOrigin::parse("30"),
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Pixels(30.0),
+            y: TransformOriginValue::Percentage(50.0),
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_transform_origin() {
+    assert_eq!(
+        TransformOrigin::parse("left right"),
+        Err(ParseTransformError)
+    );
+    assert_eq!(TransformOrigin::parse("1 2 3"), Err(ParseTransformError));
+}
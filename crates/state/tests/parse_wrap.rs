@@ -0,0 +1,20 @@
+use freya_node_state::Parse;
+use torin::wrap::WrapMode;
+
+#[test]
+fn parse_wrap_mode() {
+    let wrap = WrapMode::parse("wrap");
+    assert_eq!(wrap, Ok(WrapMode::Wrap));
+}
+
+#[test]
+fn parse_no_wrap_mode() {
+    let wrap = WrapMode::parse("no-wrap");
+    assert_eq!(wrap, Ok(WrapMode::NoWrap));
+}
+
+#[test]
+fn parse_fallback_wrap_mode() {
+    let wrap = WrapMode::parse("Hello, World!");
+    assert_eq!(wrap, Ok(WrapMode::NoWrap));
+}
@@ -121,6 +121,7 @@ impl TestingHandler {
                         }
                     }
                     EventMessage::FocusAccessibilityNode(node_id) => {
+                        let previous_focused_id = self.platform_sender.borrow().focused_id;
                         let tree = self
                             .accessibility_manager
                             .lock()
@@ -132,8 +133,10 @@ impl TestingHandler {
                                 state.focused_id = tree.focus;
                             });
                         }
+                        self.notify_focus_change(previous_focused_id);
                     }
                     EventMessage::FocusNextAccessibilityNode => {
+                        let previous_focused_id = self.platform_sender.borrow().focused_id;
                         let tree = self
                             .accessibility_manager
                             .lock()
@@ -142,8 +145,10 @@ impl TestingHandler {
                         self.platform_sender.send_modify(|state| {
                             state.focused_id = tree.focus;
                         });
+                        self.notify_focus_change(previous_focused_id);
                     }
                     EventMessage::FocusPrevAccessibilityNode => {
+                        let previous_focused_id = self.platform_sender.borrow().focused_id;
                         let tree = self
                             .accessibility_manager
                             .lock()
@@ -152,6 +157,7 @@ impl TestingHandler {
                         self.platform_sender.send_modify(|state| {
                             state.focused_id = tree.focus;
                         });
+                        self.notify_focus_change(previous_focused_id);
                     }
                     EventMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
@@ -159,6 +165,19 @@ impl TestingHandler {
                     EventMessage::RemeasureTextGroup(text_measurement) => {
                         self.measure_text_group(text_measurement);
                     }
+                    EventMessage::RenderNodeSnapshot(request) => {
+                        let snapshot = render_node_snapshot(
+                            &self.utils.sdom().get(),
+                            request.node_id,
+                            &request.config,
+                            &mut self.font_collection,
+                            &self.font_mgr,
+                            &mut ImagesCache::default(),
+                            &default_fonts(),
+                            SCALE_FACTOR as f32,
+                        );
+                        (request.callback)(snapshot);
+                    }
                     _ => {}
                 }
             }
@@ -231,6 +250,22 @@ impl TestingHandler {
         );
     }
 
+    /// Emit the focus events if the focused Node changed.
+    fn notify_focus_change(&mut self, previous_focused_id: AccessibilityId) {
+        let focused_id = self.platform_sender.borrow().focused_id;
+        let focus_events = PlatformEvent::focus_events(previous_focused_id, focused_id);
+        if !focus_events.is_empty() {
+            self.events_queue.extend(focus_events);
+            process_events(
+                &self.utils.sdom().get(),
+                &mut self.events_queue,
+                &self.event_emitter,
+                &mut self.nodes_state,
+                SCALE_FACTOR,
+            );
+        }
+    }
+
     fn measure_text_group(&self, text_measurement: TextGroupMeasurement) {
         let sdom = self.utils.sdom();
         sdom.get()
@@ -294,18 +329,24 @@ impl TestingHandler {
             canvas: surface.canvas(),
             font_collection: &mut self.font_collection,
             font_manager: &self.font_mgr,
+            images_cache: &mut ImagesCache::default(),
             matrices: Vec::default(),
             opacities: Vec::default(),
+            blurs: Vec::default(),
             default_fonts: &["Fira Sans".to_string()],
             scale_factor: SCALE_FACTOR as f32,
         };
 
         // Render to the canvas
-        process_render(&fdom, |fdom, node_id, layout_node, layout| {
-            if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
-                skia_renderer.render(layout_node, &dioxus_node, false, layout);
-            }
-        });
+        process_render(
+            &fdom,
+            &Damage::Full,
+            |fdom, node_id, layout_node, layout, is_damaged| {
+                if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
+                    skia_renderer.render(layout_node, &dioxus_node, false, layout, is_damaged);
+                }
+            },
+        );
 
         // Capture snapshot
         let image = surface.image_snapshot();
@@ -1,4 +1,5 @@
 use freya::prelude::*;
+use freya_engine::prelude::Color;
 use freya_testing::prelude::*;
 
 #[tokio::test]
@@ -62,6 +63,122 @@ async fn check_size() {
     assert_eq!(rect.area().unwrap().height(), 430.0);
 }
 
+#[tokio::test]
+async fn check_blur() {
+    fn blur_app() -> Element {
+        rsx!(
+            rect {
+                blur: "4",
+                backdrop_blur: "12.5",
+            }
+            rect {
+                blur: "-2",
+            }
+        )
+    }
+
+    let mut utils = launch_test(blur_app);
+
+    utils.wait_for_update().await;
+
+    let style = utils.root().get(0).style();
+    assert_eq!(style.blur, Some(4.0));
+    assert_eq!(style.backdrop_blur, Some(12.5));
+
+    // Negative blurs are clamped to zero
+    assert_eq!(utils.root().get(1).style().blur, Some(0.0));
+}
+
+#[tokio::test]
+async fn damage_tracking() {
+    fn damage_app() -> Element {
+        let mut enabled = use_signal(|| false);
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                background: if enabled() { "red" } else { "blue" },
+                onclick: move |_| enabled.set(true),
+            }
+            rect {
+                width: "100",
+                height: "100",
+                background: "green",
+            }
+        )
+    }
+
+    let mut utils = launch_test(damage_app);
+
+    utils.wait_for_update().await;
+
+    // The first frame is fully repainted
+    assert_eq!(process_damage(&utils.sdom().get(), 1.0), Damage::Full);
+
+    // Nothing changed since the last frame
+    assert_eq!(process_damage(&utils.sdom().get(), 1.0), Damage::None);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    // Only the area of the first rect, with room for its antialiasing, is repainted
+    assert_eq!(
+        process_damage(&utils.sdom().get(), 1.0),
+        Damage::Partial(vec![Area::new(
+            Point2D::new(-1.0, -1.0),
+            Size2D::new(102.0, 102.0)
+        )])
+    );
+}
+
+#[tokio::test]
+async fn damage_tracking_inherited_effects() {
+    fn damage_app() -> Element {
+        let mut faded = use_signal(|| false);
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                opacity: if faded() { "0.5" } else { "1" },
+                onclick: move |_| faded.set(true),
+                rect {
+                    width: "200",
+                    height: "200",
+                    background: "red",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(damage_app);
+
+    utils.wait_for_update().await;
+
+    assert_eq!(process_damage(&utils.sdom().get(), 1.0), Damage::Full);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    // The child overflowing its parent is also repainted with the new opacity
+    assert_eq!(
+        process_damage(&utils.sdom().get(), 1.0),
+        Damage::Partial(vec![Area::new(
+            Point2D::new(-1.0, -1.0),
+            Size2D::new(202.0, 202.0)
+        )])
+    );
+}
+
 #[tokio::test]
 async fn simulate_events() {
     fn stateful_app() -> Element {
@@ -137,3 +254,34 @@ async fn match_by_text() {
         Some("Hello, Rust!")
     );
 }
+
+#[tokio::test]
+async fn check_border_sides() {
+    fn border_app() -> Element {
+        rsx!(rect {
+            border: "1 solid red",
+            border_bottom: "3 solid blue",
+            border_left: "2 dashed green",
+            border_align: "outer",
+        })
+    }
+
+    let mut utils = launch_test(border_app);
+
+    utils.wait_for_update().await;
+
+    let border = utils.root().get(0).style().border;
+
+    // The sides override the shorthand no matter the order of the attributes
+    assert_eq!(border.top.width, 1.0);
+    assert_eq!(border.top.fill, Fill::Color(Color::RED));
+    assert_eq!(border.right.width, 1.0);
+    assert_eq!(border.bottom.width, 3.0);
+    assert_eq!(border.bottom.fill, Fill::Color(Color::BLUE));
+    assert_eq!(border.left.width, 2.0);
+    assert_eq!(border.left.style, BorderStyle::Dashed);
+
+    for side in [border.top, border.right, border.bottom, border.left] {
+        assert_eq!(side.alignment, BorderAlignment::Outer);
+    }
+}
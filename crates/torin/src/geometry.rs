@@ -10,6 +10,7 @@ pub struct Measure;
 pub type Area = euclid::Rect<f32, Measure>;
 pub type Size2D = euclid::Size2D<f32, Measure>;
 pub type Point2D = euclid::Point2D<f32, Measure>;
+pub type Vector2D = euclid::Vector2D<f32, Measure>;
 pub type CursorPoint = euclid::Point2D<f64, Measure>;
 pub type Length = euclid::Length<f32, Measure>;
 
@@ -19,6 +20,8 @@ pub trait AreaModel {
 
     fn move_with_offsets(&mut self, offset_x: &Length, offset_y: &Length);
 
+    fn move_to_line(&mut self, lines_area: &Area, cross_offset: f32, direction: &DirectionMode);
+
     fn align_content(
         &mut self,
         available_area: &Area,
@@ -49,6 +52,21 @@ impl AreaModel for Area {
         self.origin.y += offset_y.get();
     }
 
+    /// Move the area to the start of a wrapped line that is `cross_offset` away from the first line
+    fn move_to_line(&mut self, lines_area: &Area, cross_offset: f32, direction: &DirectionMode) {
+        *self = *lines_area;
+        match direction {
+            DirectionMode::Vertical | DirectionMode::Grid => {
+                self.origin.x += cross_offset;
+                self.size.width -= cross_offset;
+            }
+            DirectionMode::Horizontal => {
+                self.origin.y += cross_offset;
+                self.size.height -= cross_offset;
+            }
+        }
+    }
+
     fn align_content(
         &mut self,
         available_area: &Area,
@@ -87,12 +105,28 @@ impl AreaModel for Area {
     }
 }
 
+/// Get the size in the main and cross axis of the given direction
+pub fn main_and_cross_sizes(size: &Size2D, direction: &DirectionMode) -> (f32, f32) {
+    match direction {
+        DirectionMode::Vertical | DirectionMode::Grid => (size.height, size.width),
+        DirectionMode::Horizontal => (size.width, size.height),
+    }
+}
+
+/// Create a size given the sizes in the main and cross axis of the given direction
+pub fn size_from_main_and_cross(main: f32, cross: f32, direction: &DirectionMode) -> Size2D {
+    match direction {
+        DirectionMode::Vertical | DirectionMode::Grid => Size2D::new(cross, main),
+        DirectionMode::Horizontal => Size2D::new(main, cross),
+    }
+}
+
 pub fn get_align_axis(
     direction: &DirectionMode,
     alignment_direction: AlignmentDirection,
 ) -> AlignAxis {
     match direction {
-        DirectionMode::Vertical => match alignment_direction {
+        DirectionMode::Vertical | DirectionMode::Grid => match alignment_direction {
             AlignmentDirection::Main => AlignAxis::Height,
             AlignmentDirection::Cross => AlignAxis::Width,
         },
@@ -1,5 +1,8 @@
 pub use euclid::Rect;
-use rustc_hash::FxHashMap;
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
@@ -9,19 +12,111 @@ use crate::{
         NodeKey,
     },
     geometry::{
+        main_and_cross_sizes,
+        size_from_main_and_cross,
         Area,
+        Length,
+        Point2D,
         Size2D,
+        Vector2D,
     },
     measure_mode::MeasureMode,
     node::Node,
     prelude::{
+        resolve_grid_tracks,
         AlignmentDirection,
         AreaModel,
+        DirectionMode,
+        GridPlacement,
+        GridTrack,
         LayoutMetadata,
+        Size,
         Torin,
     },
 };
 
+/// A line of children stacked along the main axis of a Node that wraps its content
+#[derive(Default, Clone, Copy)]
+struct WrappedLine {
+    /// Amount of children in this line
+    children: usize,
+    /// Size of all the children in the main axis
+    main_size: f32,
+    /// Size of the biggest child in the cross axis
+    cross_size: f32,
+}
+
+/// A child that grows or shrinks along the main axis of a Node with flex content
+struct FlexItem<Key> {
+    node_id: Key,
+    /// Size in the main axis before growing or shrinking
+    base_size: f32,
+    /// Size in the main axis it can't shrink below of
+    minimum_size: f32,
+    /// Weight of the flex size, only flex sizes grow
+    grow: f32,
+    /// Weight to shrink with when the content overflows
+    shrink: f32,
+}
+
+/// Grow or shrink the given flex items so they fill the leftover size of the main axis.
+/// Growing is proportional to the flex weights, and shrinking to the shrink weights and base sizes,
+/// items that reach their minimum size stop shrinking and the rest shrink the remaining overflow.
+fn resolve_flex_sizes<Key>(items: &[FlexItem<Key>], leftover_size: f32) -> Vec<f32> {
+    let mut sizes = items
+        .iter()
+        .map(|item| item.base_size)
+        .collect::<Vec<f32>>();
+
+    if leftover_size > 0.0 {
+        let total_grow = items.iter().map(|item| item.grow).sum::<f32>();
+        if total_grow > 0.0 {
+            for (size, item) in sizes.iter_mut().zip(items) {
+                *size += leftover_size * item.grow / total_grow;
+            }
+        }
+    } else if leftover_size < 0.0 {
+        let mut overflow = -leftover_size;
+        let mut frozen = vec![false; items.len()];
+
+        loop {
+            let total_shrink = items
+                .iter()
+                .zip(&frozen)
+                .filter(|(_, frozen)| !**frozen)
+                .map(|(item, _)| item.shrink * item.base_size)
+                .sum::<f32>();
+
+            if total_shrink <= 0.0 {
+                break;
+            }
+
+            let mut any_frozen = false;
+            for (i, item) in items.iter().enumerate() {
+                if frozen[i] {
+                    continue;
+                }
+
+                let size = item.base_size - overflow * item.shrink * item.base_size / total_shrink;
+                if size < item.minimum_size {
+                    overflow = (overflow - item.base_size + item.minimum_size).max(0.0);
+                    sizes[i] = item.minimum_size;
+                    frozen[i] = true;
+                    any_frozen = true;
+                } else {
+                    sizes[i] = size;
+                }
+            }
+
+            if !any_frozen {
+                break;
+            }
+        }
+    }
+
+    sizes
+}
+
 /// Some layout strategies require two-phase measurements
 /// Example: Alignments or content-fit.
 #[derive(Clone, Copy, PartialEq)]
@@ -84,6 +179,51 @@ pub fn measure_node<Key: NodeKey>(
             phase,
         );
 
+        // Size the `auto` axis of a Node with an aspect ratio from its other axis,
+        // from now on that axis is treated as a fixed size
+        let aspect_ratio_node = node.aspect_ratio.and_then(|aspect_ratio| {
+            if aspect_ratio <= 0.0 {
+                return None;
+            }
+
+            if node.width != Size::Inner && node.height == Size::Inner {
+                area_size.height = node.height.min_max(
+                    (area_size.width - node.margin.horizontal()) / aspect_ratio,
+                    parent_area.size.height,
+                    available_parent_area.size.height,
+                    node.margin.top(),
+                    node.margin.vertical(),
+                    &node.minimum_height,
+                    &node.maximum_height,
+                    layout_metadata.root_area.height(),
+                    phase,
+                );
+                Some(Node {
+                    height: Size::Pixels(Length::new(area_size.height - node.margin.vertical())),
+                    ..node.clone()
+                })
+            } else if node.width == Size::Inner && node.height != Size::Inner {
+                area_size.width = node.width.min_max(
+                    (area_size.height - node.margin.vertical()) * aspect_ratio,
+                    parent_area.size.width,
+                    available_parent_area.size.width,
+                    node.margin.left(),
+                    node.margin.horizontal(),
+                    &node.minimum_width,
+                    &node.maximum_width,
+                    layout_metadata.root_area.width(),
+                    phase,
+                );
+                Some(Node {
+                    width: Size::Pixels(Length::new(area_size.width - node.margin.horizontal())),
+                    ..node.clone()
+                })
+            } else {
+                None
+            }
+        });
+        let node = aspect_ratio_node.as_ref().unwrap_or(node);
+
         // If available, run a custom layout measure function
         // This is useful when you use third-party libraries (e.g. rust-skia, cosmic-text) to measure text layouts
         // When a Node is measured by a custom measurer function the inner children will be skipped
@@ -196,6 +336,18 @@ pub fn measure_node<Key: NodeKey>(
             // Adjust the available area with the node offsets (mainly used by scrollviews)
             available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
+            // Scrollable Nodes are the viewport of their sticky inner Nodes
+            let scrollable_metadata;
+            let layout_metadata = if node.scrollable {
+                scrollable_metadata = LayoutMetadata {
+                    root_area: layout_metadata.root_area,
+                    scroll_viewport: Some(inner_area),
+                };
+                &scrollable_metadata
+            } else {
+                layout_metadata
+            };
+
             let mut measurement_mode = MeasureMode::ParentIsNotCached {
                 area: &mut area,
                 inner_area: &mut inner_area,
@@ -235,6 +387,18 @@ pub fn measure_node<Key: NodeKey>(
 
         available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
+        // Scrollable Nodes are the viewport of their sticky inner Nodes
+        let scrollable_metadata;
+        let layout_metadata = if node.scrollable {
+            scrollable_metadata = LayoutMetadata {
+                root_area: layout_metadata.root_area,
+                scroll_viewport: Some(layout_node.inner_area),
+            };
+            &scrollable_metadata
+        } else {
+            layout_metadata
+        };
+
         let mut measurement_mode = MeasureMode::ParentIsCached {
             inner_area: &layout_node.inner_area,
         };
@@ -287,21 +451,57 @@ pub fn measure_inner_nodes<Key: NodeKey>(
 
     invalidated_tree: bool,
 ) {
+    if parent_node.direction == DirectionMode::Grid {
+        measure_grid_nodes(
+            parent_node_id,
+            parent_node,
+            layout,
+            available_area,
+            inner_sizes,
+            measurer,
+            must_cache_inner_nodes,
+            mode,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+        );
+        return;
+    }
+
     let children = dom_adapter.children_of(parent_node_id);
 
     let mut initial_phase_sizes = FxHashMap::default();
 
+    // Lines in which the children are wrapped, only used when the parent wraps its content
+    let mut lines = Vec::<WrappedLine>::new();
+
+    // Extra space between the children, only used by distributed main alignments
+    let mut alignment_spacing = 0.0;
+
+    // Sizes in the main axis of the children that grow or shrink, only used by flex content
+    let mut flex_sizes = FxHashMap::default();
+
+    // Sticky children that were measured again, they are pinned once all the children are measured
+    let mut sticky_children = Vec::new();
+
     // Initial phase: Measure the size and position of the children if the parent has a
-    // non-start cross alignment, non-start main aligment of a fit-content.
+    // non-start cross alignment, non-start main aligment, a fit or flex content or wraps its content.
     if parent_node.cross_alignment.is_not_start()
         || parent_node.main_alignment.is_not_start()
         || parent_node.content.is_fit()
+        || parent_node.content.is_flex()
+        || parent_node.wrap.is_wrap()
     {
         let mut initial_phase_mode = mode.to_owned();
         let mut initial_phase_mode = initial_phase_mode.to_mut();
         let mut initial_phase_inner_sizes = *inner_sizes;
         let mut initial_phase_available_area = *available_area;
 
+        let mut line = WrappedLine::default();
+        let mut lines_cross_size = 0.0;
+
+        let mut flex_items = Vec::new();
+
         // 1. Measure the children
         for child_id in &children {
             let Some(child_data) = dom_adapter.get_node(child_id) else {
@@ -312,9 +512,19 @@ pub fn measure_inner_nodes<Key: NodeKey>(
                 continue;
             }
 
+            // Separate the child from its previous sibling
+            if line.children > 0 {
+                initial_phase_mode.stack_spacing(
+                    parent_node,
+                    &mut initial_phase_available_area,
+                    &mut initial_phase_inner_sizes,
+                    0.0,
+                );
+            }
+
             let inner_area = *initial_phase_mode.inner_area();
 
-            let (_, child_areas) = measure_node(
+            let (_, mut child_areas) = measure_node(
                 *child_id,
                 &child_data,
                 layout,
@@ -328,53 +538,313 @@ pub fn measure_inner_nodes<Key: NodeKey>(
                 Phase::Initial,
             );
 
+            if parent_node.wrap.is_wrap() {
+                let (child_main_size, _) =
+                    main_and_cross_sizes(&child_areas.area.size, &parent_node.direction);
+                let (available_main_size, _) = main_and_cross_sizes(
+                    &initial_phase_available_area.size,
+                    &parent_node.direction,
+                );
+
+                // Move the child to a new line if it doesn't fit in the current one
+                if line.children > 0 && child_main_size > available_main_size {
+                    lines_cross_size += line.cross_size + parent_node.spacing.get();
+                    lines.push(line);
+                    line = WrappedLine::default();
+
+                    initial_phase_available_area.move_to_line(
+                        available_area,
+                        lines_cross_size,
+                        &parent_node.direction,
+                    );
+
+                    // Measure again as now the child has the whole line available
+                    (_, child_areas) = measure_node(
+                        *child_id,
+                        &child_data,
+                        layout,
+                        &inner_area,
+                        &initial_phase_available_area,
+                        measurer,
+                        false,
+                        dom_adapter,
+                        layout_metadata,
+                        invalidated_tree,
+                        Phase::Initial,
+                    );
+                }
+            }
+
+            let (child_main_size, child_cross_size) =
+                main_and_cross_sizes(&child_areas.area.size, &parent_node.direction);
+            if line.children > 0 {
+                line.main_size += parent_node.spacing.get();
+            }
+            line.children += 1;
+            line.main_size += child_main_size;
+            line.cross_size = line.cross_size.max(child_cross_size);
+
+            if parent_node.content.is_flex() && !parent_node.wrap.is_wrap() {
+                let (child_size, child_minimum_size) = match parent_node.direction {
+                    DirectionMode::Horizontal => (&child_data.width, &child_data.minimum_width),
+                    DirectionMode::Vertical | DirectionMode::Grid => {
+                        (&child_data.height, &child_data.minimum_height)
+                    }
+                };
+                let grow = if let Size::Flex(flex) = child_size {
+                    flex.get()
+                } else {
+                    0.0
+                };
+
+                if grow > 0.0 || child_data.flex_shrink > 0.0 {
+                    let (inner_main_size, _) =
+                        main_and_cross_sizes(&inner_area.size, &parent_node.direction);
+                    let (root_main_size, _) = main_and_cross_sizes(
+                        &layout_metadata.root_area.size,
+                        &parent_node.direction,
+                    );
+                    let (single_margin, margin) = match parent_node.direction {
+                        DirectionMode::Horizontal => {
+                            (child_data.margin.left(), child_data.margin.horizontal())
+                        }
+                        DirectionMode::Vertical | DirectionMode::Grid => {
+                            (child_data.margin.top(), child_data.margin.vertical())
+                        }
+                    };
+                    let minimum_size = Size::Inner.min_max(
+                        0.0,
+                        inner_main_size,
+                        inner_main_size,
+                        single_margin,
+                        margin,
+                        child_minimum_size,
+                        &Size::Inner,
+                        root_main_size,
+                        Phase::Final,
+                    );
+
+                    flex_items.push(FlexItem {
+                        node_id: *child_id,
+                        base_size: child_main_size,
+                        minimum_size,
+                        grow,
+                        shrink: child_data.flex_shrink,
+                    });
+                }
+            }
+
+            // Sticky children are stacked by their position before being pinned
+            let stacked_area = if child_data.position.is_sticky() {
+                Rect::new(initial_phase_available_area.origin, child_areas.area.size)
+            } else {
+                child_areas.area
+            };
+
             initial_phase_mode.stack_into_node(
                 parent_node,
                 &mut initial_phase_available_area,
-                &child_areas.area,
+                &stacked_area,
                 &mut initial_phase_inner_sizes,
                 &child_data,
             );
 
-            if parent_node.cross_alignment.is_not_start() {
+            if parent_node.cross_alignment.is_not_start() || parent_node.wrap.is_wrap() {
                 initial_phase_sizes.insert(*child_id, child_areas.area.size);
             }
         }
 
-        if parent_node.main_alignment.is_not_start() {
-            // 2. Adjust the available and inner areas of the Main axis
-            initial_phase_mode.fit_bounds_when_unspecified(
-                parent_node,
-                AlignmentDirection::Main,
-                available_area,
-            );
+        if parent_node.wrap.is_wrap() {
+            if line.children > 0 {
+                lines.push(line);
+            }
 
-            // 3. Align the Main axis
-            available_area.align_content(
-                initial_phase_mode.inner_area(),
-                &initial_phase_inner_sizes,
-                &parent_node.main_alignment,
-                &parent_node.direction,
-                AlignmentDirection::Main,
-            );
-        }
+            // 2. Fit the parent to the size of its lines
+            let lines_main_size = lines.iter().map(|line| line.main_size).fold(0.0, f32::max);
+            let lines_cross_size = lines.iter().map(|line| line.cross_size).sum::<f32>()
+                + parent_node.spacing.get() * lines.len().saturating_sub(1) as f32;
+            let lines_size =
+                size_from_main_and_cross(lines_main_size, lines_cross_size, &parent_node.direction);
 
-        if parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit() {
-            // 4. Adjust the available and inner areas of the Cross axis
-            initial_phase_mode.fit_bounds_when_unspecified(
-                parent_node,
-                AlignmentDirection::Cross,
-                available_area,
-            );
+            *inner_sizes = lines_size;
+
+            mode.fit_bounds_to_content(parent_node, &lines_size, available_area);
+        } else {
+            if parent_node.content.is_flex() {
+                // 2. Grow or shrink the flex children to fit the Main axis
+                let (inner_main_size, _) = main_and_cross_sizes(
+                    &initial_phase_mode.inner_area().size,
+                    &parent_node.direction,
+                );
+                let (content_main_size, content_cross_size) =
+                    main_and_cross_sizes(&initial_phase_inner_sizes, &parent_node.direction);
+                let main_inner_sized = match parent_node.direction {
+                    DirectionMode::Horizontal => parent_node.width.inner_sized(),
+                    DirectionMode::Vertical | DirectionMode::Grid => {
+                        parent_node.height.inner_sized()
+                    }
+                };
+
+                // Nodes sized by their content have no leftover space to grow into
+                let mut leftover_size = inner_main_size - content_main_size;
+                if main_inner_sized {
+                    leftover_size = leftover_size.min(0.0);
+                }
+
+                let mut flexed_main_size = content_main_size;
+                let sizes = resolve_flex_sizes(&flex_items, leftover_size);
+                for (item, size) in flex_items.iter().zip(sizes) {
+                    flexed_main_size += size - item.base_size;
+                    flex_sizes.insert(item.node_id, size);
+                }
+
+                initial_phase_inner_sizes = size_from_main_and_cross(
+                    flexed_main_size,
+                    content_cross_size,
+                    &parent_node.direction,
+                );
+            }
+
+            if parent_node.main_alignment.is_not_start() {
+                // 3. Adjust the available and inner areas of the Main axis
+                initial_phase_mode.fit_bounds_when_unspecified(
+                    parent_node,
+                    AlignmentDirection::Main,
+                    available_area,
+                );
+
+                // 4. Align the Main axis
+                available_area.align_content(
+                    initial_phase_mode.inner_area(),
+                    &initial_phase_inner_sizes,
+                    &parent_node.main_alignment,
+                    &parent_node.direction,
+                    AlignmentDirection::Main,
+                );
+
+                // 5. Distribute the leftover space of the Main axis
+                if parent_node.main_alignment.is_distributed() {
+                    let (inner_main_size, _) = main_and_cross_sizes(
+                        &initial_phase_mode.inner_area().size,
+                        &parent_node.direction,
+                    );
+                    let (content_main_size, _) =
+                        main_and_cross_sizes(&initial_phase_inner_sizes, &parent_node.direction);
+                    let (offset, spacing) = parent_node
+                        .main_alignment
+                        .distributed_spacing(inner_main_size - content_main_size, line.children);
+                    alignment_spacing = spacing;
+                    available_area.origin +=
+                        size_from_main_and_cross(offset, 0.0, &parent_node.direction).to_vector();
+                }
+            }
+
+            if parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit() {
+                // 6. Adjust the available and inner areas of the Cross axis
+                initial_phase_mode.fit_bounds_when_unspecified(
+                    parent_node,
+                    AlignmentDirection::Cross,
+                    available_area,
+                );
+            }
         }
     }
 
+    // Area from where the wrapped lines start
+    let lines_area = *available_area;
+    let mut current_line = 0;
+    let mut stacked_in_line = 0;
+    let mut lines_cross_size = 0.0;
+
     // Final phase: measure the children with all the axis and sizes adjusted
     for child_id in children {
-        let Some(child_data) = dom_adapter.get_node(&child_id) else {
+        let Some(mut child_data) = dom_adapter.get_node(&child_id) else {
             continue;
         };
 
+        // Use the size resolved for the flex children in the main axis
+        if let Some(flex_size) = flex_sizes.get(&child_id) {
+            let (size, margin) = match parent_node.direction {
+                DirectionMode::Horizontal => {
+                    (&mut child_data.width, child_data.margin.horizontal())
+                }
+                DirectionMode::Vertical | DirectionMode::Grid => {
+                    (&mut child_data.height, child_data.margin.vertical())
+                }
+            };
+            *size = Size::Pixels(Length::new(flex_size - margin));
+        }
+
+        // Area of the line in where the child is stacked
+        let mut line_area = *available_area;
+
+        if !child_data.position.is_absolute() {
+            if parent_node.wrap.is_wrap() {
+                if let Some(line) = lines.get(current_line) {
+                    // Jump to the next line once the current one is full
+                    if stacked_in_line == line.children && current_line + 1 < lines.len() {
+                        lines_cross_size += line.cross_size + parent_node.spacing.get();
+                        current_line += 1;
+                        stacked_in_line = 0;
+                    }
+                }
+
+                if let Some(line) = lines.get(current_line) {
+                    line_area.move_to_line(&lines_area, lines_cross_size, &parent_node.direction);
+
+                    if stacked_in_line == 0 {
+                        *available_area = line_area;
+
+                        // Align the Main axis of the line
+                        if parent_node.main_alignment.is_not_start() {
+                            available_area.align_content(
+                                &line_area,
+                                &size_from_main_and_cross(
+                                    line.main_size,
+                                    line.cross_size,
+                                    &parent_node.direction,
+                                ),
+                                &parent_node.main_alignment,
+                                &parent_node.direction,
+                                AlignmentDirection::Main,
+                            );
+
+                            // Distribute the leftover space of the Main axis of the line
+                            if parent_node.main_alignment.is_distributed() {
+                                let (line_main_size, _) =
+                                    main_and_cross_sizes(&line_area.size, &parent_node.direction);
+                                let (offset, spacing) =
+                                    parent_node.main_alignment.distributed_spacing(
+                                        line_main_size - line.main_size,
+                                        line.children,
+                                    );
+                                alignment_spacing = spacing;
+                                available_area.origin +=
+                                    size_from_main_and_cross(offset, 0.0, &parent_node.direction)
+                                        .to_vector();
+                            }
+                        }
+                    }
+
+                    // Constrain the Cross axis to the line
+                    let (line_main_size, _) =
+                        main_and_cross_sizes(&line_area.size, &parent_node.direction);
+                    line_area.size = size_from_main_and_cross(
+                        line_main_size,
+                        line.cross_size,
+                        &parent_node.direction,
+                    );
+                }
+            }
+
+            // Separate the child from its previous sibling
+            if stacked_in_line > 0 {
+                mode.stack_spacing(parent_node, available_area, inner_sizes, alignment_spacing);
+            }
+
+            stacked_in_line += 1;
+        }
+
         let mut adapted_available_area = *available_area;
         if parent_node.cross_alignment.is_not_start() {
             let initial_phase_size = initial_phase_sizes.get(&child_id);
@@ -382,7 +852,7 @@ pub fn measure_inner_nodes<Key: NodeKey>(
             if let Some(initial_phase_size) = initial_phase_size {
                 // 1. Align the Cross axis if necessary
                 adapted_available_area.align_content(
-                    available_area,
+                    &line_area,
                     initial_phase_size,
                     &parent_node.cross_alignment,
                     &parent_node.direction,
@@ -408,17 +878,28 @@ pub fn measure_inner_nodes<Key: NodeKey>(
             Phase::Final,
         );
 
+        // Sticky children are stacked by their position before being pinned
+        let stacked_area = if child_data.position.is_sticky() {
+            Rect::new(adapted_available_area.origin, child_areas.area.size)
+        } else {
+            child_areas.area
+        };
+
         // Stack the child into its parent
         mode.stack_into_node(
             parent_node,
             available_area,
-            &child_areas.area,
+            &stacked_area,
             inner_sizes,
             &child_data,
         );
 
         // Cache the child layout if it was mutated and inner nodes must be cache
         if child_revalidated && must_cache_inner_nodes {
+            if child_data.position.is_sticky() {
+                sticky_children.push((child_id, child_data.position.clone(), child_areas.area));
+            }
+
             if let Some(measurer) = measurer {
                 if child_data.has_layout_references {
                     measurer.notify_layout_references(child_id, &child_areas);
@@ -427,4 +908,399 @@ pub fn measure_inner_nodes<Key: NodeKey>(
             layout.cache_node(child_id, child_areas);
         }
     }
+
+    // Pin the sticky children to the scroll viewport now that the area of their parent is known
+    if let Some(scroll_viewport) = layout_metadata.scroll_viewport {
+        if sticky_children.is_empty() {
+            return;
+        }
+
+        let parent_area = if let MeasureMode::ParentIsNotCached { area, .. } = mode {
+            **area
+        } else if let Some(layout_node) = layout.get(*parent_node_id) {
+            layout_node.area
+        } else {
+            return;
+        };
+        let parent_inner_area = parent_area
+            .after_gaps(&parent_node.padding)
+            .after_gaps(&parent_node.margin);
+
+        for (child_id, position, area) in sticky_children {
+            let offset = position.sticky_offset(&area, &parent_inner_area, &scroll_viewport);
+            if offset == Vector2D::zero() {
+                continue;
+            }
+
+            translate_node(child_id, offset, layout, dom_adapter);
+
+            if let Some(measurer) = measurer {
+                if let (Some(child_data), Some(child_areas)) =
+                    (dom_adapter.get_node(&child_id), layout.get(child_id))
+                {
+                    if child_data.has_layout_references {
+                        measurer.notify_layout_references(child_id, child_areas);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Move the layout of a Node and all its inner Nodes by the given offset
+fn translate_node<Key: NodeKey>(
+    node_id: Key,
+    offset: Vector2D,
+    layout: &mut Torin<Key>,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+) {
+    if let Some(layout_node) = layout.results.get_mut(&node_id) {
+        layout_node.area = layout_node.area.translate(offset);
+        layout_node.inner_area = layout_node.inner_area.translate(offset);
+    }
+
+    for child_id in dom_adapter.children_of(&node_id) {
+        translate_node(child_id, offset, layout, dom_adapter);
+    }
+}
+
+/// Measure the children layouts of a Node that places them in a grid
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+pub fn measure_grid_nodes<Key: NodeKey>(
+    parent_node_id: &Key,
+    parent_node: &Node,
+    layout: &mut Torin<Key>,
+    // Area available inside the Node
+    available_area: &mut Area,
+    // Accumulated sizes in both axis in the Node
+    inner_sizes: &mut Size2D,
+    measurer: &mut Option<impl LayoutMeasurer<Key>>,
+    // Whether to cache the measurements of this Node's children
+    must_cache_inner_nodes: bool,
+    mode: &mut MeasureMode,
+    // Adapter for the provided DOM
+    dom_adapter: &mut impl DOMAdapter<Key>,
+
+    layout_metadata: &LayoutMetadata,
+
+    invalidated_tree: bool,
+) {
+    let children = dom_adapter.children_of(parent_node_id);
+
+    // 1. Place the children in the cells of the grid
+    let mut cells = GridCells::new(parent_node.grid_columns.len().max(1));
+    let mut placements = FxHashMap::default();
+    for child_id in &children {
+        let Some(child_data) = dom_adapter.get_node(child_id) else {
+            continue;
+        };
+
+        if child_data.position.is_absolute() {
+            continue;
+        }
+
+        let placement = cells.place(&child_data.grid_column, &child_data.grid_row);
+        placements.insert(*child_id, (child_data, placement));
+    }
+
+    // Tracks not defined by the parent are sized by their inner Nodes
+    let mut column_tracks = parent_node.grid_columns.clone();
+    column_tracks.resize(cells.columns.max(column_tracks.len()), GridTrack::Inner);
+    let mut row_tracks = parent_node.grid_rows.clone();
+    row_tracks.resize(cells.rows.max(row_tracks.len()), GridTrack::Inner);
+
+    let inner_area = *mode.inner_area();
+
+    // 2. Measure the columns
+    let mut column_inner_sizes = vec![0.0f32; column_tracks.len()];
+    for child_id in &children {
+        let Some((child_data, placement)) = placements.get(child_id) else {
+            continue;
+        };
+
+        if placement.column_span > 1
+            || !column_tracks[placement.column].inner_sized(parent_node.width.inner_sized())
+        {
+            continue;
+        }
+
+        let (_, child_areas) = measure_node(
+            *child_id,
+            child_data,
+            layout,
+            &inner_area,
+            &inner_area,
+            measurer,
+            false,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+            Phase::Initial,
+        );
+
+        let inner_size = &mut column_inner_sizes[placement.column];
+        *inner_size = inner_size.max(child_areas.area.width());
+    }
+    let column_sizes = resolve_grid_tracks(
+        &column_tracks,
+        &column_inner_sizes,
+        inner_area.width(),
+        parent_node.spacing.get(),
+        parent_node.width.inner_sized(),
+    );
+
+    // 3. Measure the rows now that the width of every cell is known
+    let mut row_inner_sizes = vec![0.0f32; row_tracks.len()];
+    for child_id in &children {
+        let Some((child_data, placement)) = placements.get(child_id) else {
+            continue;
+        };
+
+        if placement.row_span > 1
+            || !row_tracks[placement.row].inner_sized(parent_node.height.inner_sized())
+        {
+            continue;
+        }
+
+        let mut cell_area = inner_area;
+        cell_area.size.width = placement.width(&column_sizes, parent_node.spacing.get());
+
+        let (_, child_areas) = measure_node(
+            *child_id,
+            child_data,
+            layout,
+            &cell_area,
+            &cell_area,
+            measurer,
+            false,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+            Phase::Initial,
+        );
+
+        let inner_size = &mut row_inner_sizes[placement.row];
+        *inner_size = inner_size.max(child_areas.area.height());
+    }
+    let row_sizes = resolve_grid_tracks(
+        &row_tracks,
+        &row_inner_sizes,
+        inner_area.height(),
+        parent_node.spacing.get(),
+        parent_node.height.inner_sized(),
+    );
+
+    // 4. Fit the parent to the size of all the tracks and the spacing between them
+    let content_size = Size2D::new(
+        tracks_size(&column_sizes, parent_node.spacing.get()),
+        tracks_size(&row_sizes, parent_node.spacing.get()),
+    );
+    *inner_sizes = content_size;
+    mode.fit_bounds_to_content(parent_node, &content_size, available_area);
+
+    // Final phase: measure the children inside their cells
+    for child_id in children {
+        let (child_data, parent_area, adapted_available_area) =
+            if let Some((child_data, placement)) = placements.remove(&child_id) {
+                let spacing = parent_node.spacing.get();
+                let cell_area = Area::new(
+                    Point2D::new(
+                        available_area.min_x() + placement.x(&column_sizes, spacing),
+                        available_area.min_y() + placement.y(&row_sizes, spacing),
+                    ),
+                    Size2D::new(
+                        placement.width(&column_sizes, spacing),
+                        placement.height(&row_sizes, spacing),
+                    ),
+                );
+
+                let mut adapted_available_area = cell_area;
+
+                // Align the child inside its cell
+                if parent_node.main_alignment.is_not_start()
+                    || parent_node.cross_alignment.is_not_start()
+                {
+                    let (_, child_areas) = measure_node(
+                        child_id,
+                        &child_data,
+                        layout,
+                        &cell_area,
+                        &cell_area,
+                        measurer,
+                        false,
+                        dom_adapter,
+                        layout_metadata,
+                        invalidated_tree,
+                        Phase::Initial,
+                    );
+
+                    adapted_available_area.align_content(
+                        &cell_area,
+                        &child_areas.area.size,
+                        &parent_node.main_alignment,
+                        &parent_node.direction,
+                        AlignmentDirection::Main,
+                    );
+                    adapted_available_area.align_content(
+                        &cell_area,
+                        &child_areas.area.size,
+                        &parent_node.cross_alignment,
+                        &parent_node.direction,
+                        AlignmentDirection::Cross,
+                    );
+                }
+
+                (child_data, cell_area, adapted_available_area)
+            } else {
+                let Some(child_data) = dom_adapter.get_node(&child_id) else {
+                    continue;
+                };
+
+                (child_data, *mode.inner_area(), *available_area)
+            };
+
+        // Final measurement
+        let (child_revalidated, child_areas) = measure_node(
+            child_id,
+            &child_data,
+            layout,
+            &parent_area,
+            &adapted_available_area,
+            measurer,
+            must_cache_inner_nodes,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+            Phase::Final,
+        );
+
+        // Cache the child layout if it was mutated and inner nodes must be cache
+        if child_revalidated && must_cache_inner_nodes {
+            if let Some(measurer) = measurer {
+                if child_data.has_layout_references {
+                    measurer.notify_layout_references(child_id, &child_areas);
+                }
+            }
+            layout.cache_node(child_id, child_areas);
+        }
+    }
+}
+
+/// Placement of a Node in the cells of a grid
+struct GridCell {
+    column: usize,
+    column_span: usize,
+    row: usize,
+    row_span: usize,
+}
+
+impl GridCell {
+    /// Offset of this cell in the X axis
+    fn x(&self, column_sizes: &[f32], spacing: f32) -> f32 {
+        column_sizes[..self.column].iter().sum::<f32>() + spacing * self.column as f32
+    }
+
+    /// Offset of this cell in the Y axis
+    fn y(&self, row_sizes: &[f32], spacing: f32) -> f32 {
+        row_sizes[..self.row].iter().sum::<f32>() + spacing * self.row as f32
+    }
+
+    /// Width of the columns ocuppied by this cell
+    fn width(&self, column_sizes: &[f32], spacing: f32) -> f32 {
+        tracks_size(
+            &column_sizes[self.column..self.column + self.column_span],
+            spacing,
+        )
+    }
+
+    /// Height of the rows ocuppied by this cell
+    fn height(&self, row_sizes: &[f32], spacing: f32) -> f32 {
+        tracks_size(&row_sizes[self.row..self.row + self.row_span], spacing)
+    }
+}
+
+/// Size of some consecutive tracks including the spacing between them
+fn tracks_size(sizes: &[f32], spacing: f32) -> f32 {
+    sizes.iter().sum::<f32>() + spacing * sizes.len().saturating_sub(1) as f32
+}
+
+/// Occupied cells of a grid, used to automatically place the Nodes without an explicit placement
+struct GridCells {
+    occupied: FxHashSet<(usize, usize)>,
+    /// Amount of columns to fill before moving to the next row
+    defined_columns: usize,
+    columns: usize,
+    rows: usize,
+    cursor: (usize, usize),
+}
+
+impl GridCells {
+    fn new(defined_columns: usize) -> Self {
+        Self {
+            occupied: FxHashSet::default(),
+            defined_columns,
+            columns: 0,
+            rows: 0,
+            cursor: (0, 0),
+        }
+    }
+
+    fn is_free(&self, column: usize, row: usize, column_span: usize, row_span: usize) -> bool {
+        (column..column + column_span)
+            .all(|column| (row..row + row_span).all(|row| !self.occupied.contains(&(column, row))))
+    }
+
+    /// Find the cell for the given placement and mark it as occupied
+    fn place(&mut self, column: &GridPlacement, row: &GridPlacement) -> GridCell {
+        let (column_span, row_span) = (column.span, row.span);
+
+        let (column, row) = match (column.start, row.start) {
+            (Some(column), Some(row)) => (column, row),
+            (Some(column), None) => {
+                let mut row = 0;
+                while !self.is_free(column, row, column_span, row_span) {
+                    row += 1;
+                }
+                (column, row)
+            }
+            (None, Some(row)) => {
+                let mut column = 0;
+                while !self.is_free(column, row, column_span, row_span) {
+                    column += 1;
+                }
+                (column, row)
+            }
+            (None, None) => {
+                let (mut column, mut row) = self.cursor;
+                loop {
+                    if column > 0 && column + column_span > self.defined_columns {
+                        column = 0;
+                        row += 1;
+                    } else if self.is_free(column, row, column_span, row_span) {
+                        break;
+                    } else {
+                        column += 1;
+                    }
+                }
+                self.cursor = (column + column_span, row);
+                (column, row)
+            }
+        };
+
+        for occupied_column in column..column + column_span {
+            for occupied_row in row..row + row_span {
+                self.occupied.insert((occupied_column, occupied_row));
+            }
+        }
+        self.columns = self.columns.max(column + column_span);
+        self.rows = self.rows.max(row + row_span);
+
+        GridCell {
+            column,
+            column_span,
+            row,
+            row_span,
+        }
+    }
 }
@@ -69,7 +69,7 @@ impl<'a> MeasureMode<'a> {
 
         let axis = get_align_axis(&parent_node.direction, alignment_direction);
         let (is_vertical_not_start, is_horizontal_not_start) = match parent_node.direction {
-            DirectionMode::Vertical => (
+            DirectionMode::Vertical | DirectionMode::Grid => (
                 parent_node.main_alignment.is_not_start(),
                 parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit(),
             ),
@@ -133,6 +133,97 @@ impl<'a> MeasureMode<'a> {
         }
     }
 
+    /// Fit the area and inner area of a parent node to the size of its content when its inner Nodes aren't simply stacked,
+    /// e.g. wrapped lines, where the main axis is as big as the longest line and the cross axis is the sum of all the lines,
+    /// or grids, where each axis is the sum of all of its tracks.
+    /// The available area is synced with the new inner area so the content can be aligned relatively to it.
+    pub fn fit_bounds_to_content(
+        &mut self,
+        parent_node: &Node,
+        content_size: &Size2D,
+        available_area: &mut Area,
+    ) {
+        if let MeasureMode::ParentIsNotCached { area, inner_area } = self {
+            if parent_node.width.inner_sized() {
+                area.size.width = area.size.width.max(
+                    content_size.width
+                        + parent_node.padding.horizontal()
+                        + parent_node.margin.horizontal(),
+                );
+                inner_area.size.width = area.size.width
+                    - parent_node.padding.horizontal()
+                    - parent_node.margin.horizontal();
+                available_area.size.width = inner_area.size.width;
+            }
+
+            if parent_node.height.inner_sized() {
+                area.size.height = area.size.height.max(
+                    content_size.height
+                        + parent_node.padding.vertical()
+                        + parent_node.margin.vertical(),
+                );
+                inner_area.size.height = area.size.height
+                    - parent_node.padding.vertical()
+                    - parent_node.margin.vertical();
+                available_area.size.height = inner_area.size.height;
+            }
+        }
+    }
+
+    /// Separate two stacked Nodes by the spacing of their parent Node.
+    /// The alignment spacing is only used to move the available area, as it comes from
+    /// distributing the leftover space of the parent Node and therefore doesn't occupy any size.
+    pub fn stack_spacing(
+        &mut self,
+        parent_node: &Node,
+        available_area: &mut Area,
+        inner_sizes: &mut Size2D,
+        alignment_spacing: f32,
+    ) {
+        let spacing = parent_node.spacing.get();
+
+        match parent_node.direction {
+            DirectionMode::Horizontal => {
+                // Move the available area
+                available_area.origin.x += spacing + alignment_spacing;
+                available_area.size.width -= spacing + alignment_spacing;
+
+                // Wrapped content is fitted by lines instead
+                if parent_node.wrap.is_wrap() {
+                    return;
+                }
+
+                if let MeasureMode::ParentIsNotCached { area, .. } = self {
+                    inner_sizes.width += spacing;
+
+                    // Accumulate width
+                    if parent_node.width.inner_sized() {
+                        area.size.width += spacing;
+                    }
+                }
+            }
+            DirectionMode::Vertical | DirectionMode::Grid => {
+                // Move the available area
+                available_area.origin.y += spacing + alignment_spacing;
+                available_area.size.height -= spacing + alignment_spacing;
+
+                // Wrapped content is fitted by lines instead
+                if parent_node.wrap.is_wrap() {
+                    return;
+                }
+
+                if let MeasureMode::ParentIsNotCached { area, .. } = self {
+                    inner_sizes.height += spacing;
+
+                    // Accumulate height
+                    if parent_node.height.inner_sized() {
+                        area.size.height += spacing;
+                    }
+                }
+            }
+        }
+    }
+
     /// Stack a Node into another Node
     pub fn stack_into_node(
         &mut self,
@@ -152,6 +243,11 @@ impl<'a> MeasureMode<'a> {
                 available_area.origin.x = content_area.max_x();
                 available_area.size.width -= content_area.size.width;
 
+                // Wrapped content is fitted by lines instead
+                if parent_node.wrap.is_wrap() {
+                    return;
+                }
+
                 if let MeasureMode::ParentIsNotCached { area, inner_area } = self {
                     inner_sizes.height = content_area.height().max(inner_sizes.height);
                     inner_sizes.width += content_area.width();
@@ -175,11 +271,16 @@ impl<'a> MeasureMode<'a> {
                     }
                 }
             }
-            DirectionMode::Vertical => {
+            DirectionMode::Vertical | DirectionMode::Grid => {
                 // Move the available area
                 available_area.origin.y = content_area.max_y();
                 available_area.size.height -= content_area.size.height;
 
+                // Wrapped content is fitted by lines instead
+                if parent_node.wrap.is_wrap() {
+                    return;
+                }
+
                 if let MeasureMode::ParentIsNotCached { area, inner_area } = self {
                     inner_sizes.width = content_area.width().max(inner_sizes.width);
                     inner_sizes.height += content_area.height();
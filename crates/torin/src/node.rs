@@ -7,7 +7,10 @@ use crate::{
     geometry::Length,
     prelude::{
         Content,
+        GridPlacement,
+        GridTrack,
         Position,
+        WrapMode,
     },
     scaled::Scaled,
     size::Size,
@@ -28,6 +31,9 @@ pub struct Node {
     pub maximum_width: Size,
     pub maximum_height: Size,
 
+    /// Ratio between the width and the height, used to size an `auto` axis from the other one
+    pub aspect_ratio: Option<f32>,
+
     // Axis alignments for the children
     pub main_alignment: Alignment,
     pub cross_alignment: Alignment,
@@ -38,18 +44,38 @@ pub struct Node {
     /// Inner margin
     pub margin: Gaps,
 
+    /// Space between the inner Nodes
+    pub spacing: Length,
+
     /// Inner position offsets
     pub offset_x: Length,
     pub offset_y: Length,
 
+    /// Whether the inner Nodes are scrolled by the offsets, sticky inner Nodes are pinned to its inner area
+    pub scrollable: bool,
+
     /// Direction in which it's inner Nodes will be stacked
     pub direction: DirectionMode,
 
+    /// Whether the inner Nodes will be wrapped into new lines when they overflow
+    pub wrap: WrapMode,
+
+    /// Tracks of the grid in which the inner Nodes are placed, only used in the grid direction
+    pub grid_columns: Vec<GridTrack>,
+    pub grid_rows: Vec<GridTrack>,
+
+    /// Placement inside the parent grid
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
+
     /// Position config
     pub position: Position,
 
     pub content: Content,
 
+    /// How much this Node shrinks relatively to its siblings when they overflow a parent with flex content
+    pub flex_shrink: f32,
+
     /// A Node might depend on inner sizes but have a fixed position, like scroll views.
     pub has_layout_references: bool,
 
@@ -65,10 +91,17 @@ impl Scaled for Node {
         self.maximum_width.scale(scale_factor);
         self.maximum_height.scale(scale_factor);
         self.margin.scale(scale_factor);
+        self.spacing *= scale_factor;
         self.padding.scale(scale_factor);
         self.offset_x *= scale_factor;
         self.offset_y *= scale_factor;
         self.position.scale(scale_factor);
+        self.grid_columns
+            .iter_mut()
+            .for_each(|track| track.scale(scale_factor));
+        self.grid_rows
+            .iter_mut()
+            .for_each(|track| track.scale(scale_factor));
     }
 }
 
@@ -100,6 +133,7 @@ impl Node {
             height,
             offset_x,
             offset_y,
+            scrollable: true,
             ..Default::default()
         }
     }
@@ -188,6 +222,71 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size, a direction and a wrap mode
+    pub fn from_size_and_direction_and_wrap(
+        width: Size,
+        height: Size,
+        direction: DirectionMode,
+        wrap: WrapMode,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction,
+            wrap,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and the tracks of a grid
+    pub fn from_size_and_grid(
+        width: Size,
+        height: Size,
+        grid_columns: Vec<GridTrack>,
+        grid_rows: Vec<GridTrack>,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction: DirectionMode::Grid,
+            grid_columns,
+            grid_rows,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and a placement inside a grid
+    pub fn from_size_and_grid_placement(
+        width: Size,
+        height: Size,
+        grid_column: GridPlacement,
+        grid_row: GridPlacement,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            grid_column,
+            grid_row,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size, a direction and some spacing
+    pub fn from_size_and_direction_and_spacing(
+        width: Size,
+        height: Size,
+        direction: DirectionMode,
+        spacing: Length,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction,
+            spacing,
+            ..Default::default()
+        }
+    }
+
     /// Construct a new Node given a size and content
     pub fn from_size_and_content(width: Size, height: Size, content: Content) -> Self {
         Self {
@@ -198,6 +297,42 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size, a direction and content
+    pub fn from_size_and_direction_and_content(
+        width: Size,
+        height: Size,
+        direction: DirectionMode,
+        content: Content,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction,
+            content,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and an aspect ratio
+    pub fn from_size_and_aspect_ratio(width: Size, height: Size, aspect_ratio: f32) -> Self {
+        Self {
+            width,
+            height,
+            aspect_ratio: Some(aspect_ratio),
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and a flex shrink
+    pub fn from_size_and_flex_shrink(width: Size, height: Size, flex_shrink: f32) -> Self {
+        Self {
+            width,
+            height,
+            flex_shrink,
+            ..Default::default()
+        }
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized()
@@ -206,5 +341,8 @@ impl Node {
             || self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
             || self.contains_text
+            || self.wrap.is_wrap()
+            || self.direction == DirectionMode::Grid
+            || self.content.is_flex()
     }
 }
@@ -33,6 +33,8 @@ use crate::{
 
 pub struct LayoutMetadata {
     pub root_area: Area,
+    /// Inner area of the nearest scrollable ancestor, sticky Nodes are pinned to it
+    pub scroll_viewport: Option<Area>,
 }
 
 /// Contains the best Root node candidate from where to start measuring
@@ -265,7 +267,24 @@ impl<Key: NodeKey> Torin<Key> {
             root_height
         );
 
-        let metadata = LayoutMetadata { root_area };
+        // Find the viewport of the nearest scrollable ancestor of the root Node
+        let mut scroll_viewport = None;
+        let mut ancestor_id = root_parent_id;
+        while let Some(id) = ancestor_id {
+            if dom_adapter
+                .get_node(&id)
+                .is_some_and(|node| node.scrollable)
+            {
+                scroll_viewport = self.get(id).map(|layout_node| layout_node.inner_area);
+                break;
+            }
+            ancestor_id = dom_adapter.parent_of(&id);
+        }
+
+        let metadata = LayoutMetadata {
+            root_area,
+            scroll_viewport,
+        };
 
         let mut available_area = layout_node.inner_area;
         if let Some(root_parent_id) = root_parent_id {
@@ -4,6 +4,9 @@ pub enum Alignment {
     Start,
     Center,
     End,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
 }
 
 impl Alignment {
@@ -11,11 +14,41 @@ impl Alignment {
         *self != Self::Start
     }
 
+    /// Whether this alignment distributes the leftover space between the Nodes
+    pub fn is_distributed(&self) -> bool {
+        matches!(
+            self,
+            Self::SpaceBetween | Self::SpaceAround | Self::SpaceEvenly
+        )
+    }
+
+    /// Get the offset of the first Node and the extra space between each Node
+    /// when distributing the leftover space of an axis between the given amount of Nodes
+    pub fn distributed_spacing(&self, leftover_size: f32, nodes: usize) -> (f32, f32) {
+        let leftover_size = leftover_size.max(0.0);
+        let nodes = nodes as f32;
+        match self {
+            Self::SpaceBetween if nodes > 1.0 => (0.0, leftover_size / (nodes - 1.0)),
+            Self::SpaceAround if nodes > 0.0 => {
+                let spacing = leftover_size / nodes;
+                (spacing / 2.0, spacing)
+            }
+            Self::SpaceEvenly => {
+                let spacing = leftover_size / (nodes + 1.0);
+                (spacing, spacing)
+            }
+            _ => (0.0, 0.0),
+        }
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Alignment::Start => "start".to_string(),
             Alignment::Center => "center".to_string(),
             Alignment::End => "end".to_string(),
+            Alignment::SpaceBetween => "space-between".to_string(),
+            Alignment::SpaceAround => "space-around".to_string(),
+            Alignment::SpaceEvenly => "space-evenly".to_string(),
         }
     }
 }
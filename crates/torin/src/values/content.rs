@@ -3,10 +3,15 @@ pub enum Content {
     #[default]
     Normal,
     Fit,
+    Flex,
 }
 
 impl Content {
     pub fn is_fit(&self) -> bool {
         self == &Self::Fit
     }
+
+    pub fn is_flex(&self) -> bool {
+        self == &Self::Flex
+    }
 }
@@ -3,6 +3,7 @@ pub enum DirectionMode {
     #[default]
     Vertical,
     Horizontal,
+    Grid,
 }
 
 impl DirectionMode {
@@ -10,6 +11,7 @@ impl DirectionMode {
         match self {
             DirectionMode::Horizontal => "horizontal".to_string(),
             DirectionMode::Vertical => "vertical".to_string(),
+            DirectionMode::Grid => "grid".to_string(),
         }
     }
 }
@@ -0,0 +1,122 @@
+use crate::{
+    geometry::Length,
+    scaled::Scaled,
+};
+
+/// Size of a column or a row of a grid
+#[derive(PartialEq, Clone, Debug)]
+pub enum GridTrack {
+    /// Sized by its biggest inner Node
+    Inner,
+    Pixels(Length),
+    Percentage(Length),
+    /// Proportional part of the space left by the other tracks
+    Weight(f32),
+}
+
+impl GridTrack {
+    /// Whether this track is sized by its inner Nodes
+    pub fn inner_sized(&self, parent_inner_sized: bool) -> bool {
+        match self {
+            GridTrack::Inner => true,
+            GridTrack::Weight(_) => parent_inner_sized,
+            _ => false,
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            GridTrack::Inner => "auto".to_string(),
+            GridTrack::Pixels(s) => format!("{}", s.get()),
+            GridTrack::Percentage(p) => format!("{}%", p.get()),
+            GridTrack::Weight(w) => format!("{w}fr"),
+        }
+    }
+}
+
+impl Scaled for GridTrack {
+    fn scale(&mut self, scale_factor: f32) {
+        if let GridTrack::Pixels(s) = self {
+            *s *= scale_factor;
+        }
+    }
+}
+
+/// Placement of a Node inside its parent grid along one of the axis
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct GridPlacement {
+    /// Index of the first track, placed automatically when not specified
+    pub start: Option<usize>,
+    /// Amount of tracks ocuppied
+    pub span: usize,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            start: None,
+            span: 1,
+        }
+    }
+}
+
+impl GridPlacement {
+    pub fn new(start: usize, span: usize) -> Self {
+        Self {
+            start: Some(start),
+            span: span.max(1),
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self.start {
+            Some(start) => format!("{start} span {}", self.span),
+            None => format!("auto span {}", self.span),
+        }
+    }
+}
+
+/// Resolve the size of every track given the space available in that axis, the spacing between tracks
+/// and the sizes of the biggest Nodes that only span a single track.
+pub fn resolve_grid_tracks(
+    tracks: &[GridTrack],
+    inner_sizes: &[f32],
+    available_size: f32,
+    spacing: f32,
+    inner_sized: bool,
+) -> Vec<f32> {
+    let mut sizes = tracks
+        .iter()
+        .zip(inner_sizes)
+        .map(|(track, inner_size)| match track {
+            GridTrack::Pixels(px) => px.get(),
+            GridTrack::Percentage(per) => available_size / 100.0 * per.get(),
+            GridTrack::Weight(_) if !inner_sized => 0.0,
+            GridTrack::Inner | GridTrack::Weight(_) => *inner_size,
+        })
+        .collect::<Vec<f32>>();
+
+    // Weighted tracks split the space left by the rest of tracks
+    // unless the parent is sized by its inner Nodes
+    if !inner_sized {
+        let total_weight = tracks
+            .iter()
+            .filter_map(|track| match track {
+                GridTrack::Weight(weight) => Some(*weight),
+                _ => None,
+            })
+            .sum::<f32>();
+        let total_spacing = spacing * tracks.len().saturating_sub(1) as f32;
+        let remaining_size = (available_size - sizes.iter().sum::<f32>() - total_spacing).max(0.0);
+
+        if total_weight > 0.0 {
+            for (track, size) in tracks.iter().zip(sizes.iter_mut()) {
+                if let GridTrack::Weight(weight) = track {
+                    *size = remaining_size * weight / total_weight;
+                }
+            }
+        }
+    }
+
+    sizes
+}
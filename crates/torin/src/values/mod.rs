@@ -2,8 +2,10 @@ pub mod alignment;
 pub mod content;
 pub mod direction;
 pub mod gaps;
+pub mod grid;
 pub mod position;
 pub mod size;
+pub mod wrap;
 
 pub mod prelude {
     pub use crate::{
@@ -11,7 +13,9 @@ pub mod prelude {
         content::*,
         direction::*,
         gaps::*,
+        grid::*,
         position::*,
         size::*,
+        wrap::*,
     };
 }
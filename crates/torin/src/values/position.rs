@@ -5,6 +5,7 @@ use crate::{
         Area,
         Point2D,
         Size2D,
+        Vector2D,
     },
     scaled::Scaled,
 };
@@ -23,6 +24,9 @@ pub enum Position {
     Stacked,
 
     Absolute(Box<AbsolutePosition>),
+
+    /// Stacked, but pinned to the edges of the nearest scrollable ancestor while its parent is visible
+    Sticky(Box<AbsolutePosition>),
 }
 
 impl Position {
@@ -37,7 +41,7 @@ impl Position {
                 } = absolute_position.deref();
                 top.is_some() && right.is_some() && bottom.is_some() && left.is_some()
             }
-            Self::Stacked => true,
+            Self::Stacked | Self::Sticky(_) => true,
         }
     }
 
@@ -50,44 +54,50 @@ impl Position {
         }))
     }
 
+    pub fn new_sticky() -> Self {
+        Self::Sticky(Box::default())
+    }
+
     pub fn is_absolute(&self) -> bool {
         matches!(self, Self::Absolute { .. })
     }
 
-    pub fn set_top(&mut self, value: f32) {
-        if !self.is_absolute() {
-            *self = Self::new_absolute();
-        }
-        if let Self::Absolute(absolute_position) = self {
-            absolute_position.top = Some(value)
+    pub fn is_sticky(&self) -> bool {
+        matches!(self, Self::Sticky { .. })
+    }
+
+    /// Turn into a sticky position, keeping the edges that were already set
+    pub fn make_sticky(&mut self) {
+        *self = match std::mem::take(self) {
+            Self::Absolute(position) | Self::Sticky(position) => Self::Sticky(position),
+            Self::Stacked => Self::new_sticky(),
         }
     }
 
-    pub fn set_right(&mut self, value: f32) {
-        if !self.is_absolute() {
+    fn edges_mut(&mut self) -> &mut AbsolutePosition {
+        if !self.is_absolute() && !self.is_sticky() {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
-            absolute_position.right = Some(value)
+        match self {
+            Self::Absolute(position) | Self::Sticky(position) => position,
+            Self::Stacked => unreachable!(),
         }
     }
 
+    pub fn set_top(&mut self, value: f32) {
+        self.edges_mut().top = Some(value)
+    }
+
+    pub fn set_right(&mut self, value: f32) {
+        self.edges_mut().right = Some(value)
+    }
+
     pub fn set_bottom(&mut self, value: f32) {
-        if !self.is_absolute() {
-            *self = Self::new_absolute();
-        }
-        if let Self::Absolute(absolute_position) = self {
-            absolute_position.bottom = Some(value)
-        }
+        self.edges_mut().bottom = Some(value)
     }
 
     pub fn set_left(&mut self, value: f32) {
-        if !self.is_absolute() {
-            *self = Self::new_absolute();
-        }
-        if let Self::Absolute(absolute_position) = self {
-            absolute_position.left = Some(value)
-        }
+        self.edges_mut().left = Some(value)
     }
 
     pub fn get_origin(
@@ -97,7 +107,7 @@ impl Position {
         area_size: &Size2D,
     ) -> Point2D {
         match self {
-            Position::Stacked => available_parent_area.origin,
+            Position::Stacked | Position::Sticky(_) => available_parent_area.origin,
             Position::Absolute(absolute_position) => {
                 let AbsolutePosition {
                     top,
@@ -127,11 +137,47 @@ impl Position {
             }
         }
     }
+
+    /// Offset that pins a sticky Node to the edges of the given viewport without leaving its parent area.
+    /// Non-sticky Nodes are never offset.
+    pub fn sticky_offset(&self, area: &Area, parent_area: &Area, viewport: &Area) -> Vector2D {
+        let Self::Sticky(sticky_position) = self else {
+            return Vector2D::zero();
+        };
+        let AbsolutePosition {
+            top,
+            right,
+            bottom,
+            left,
+        } = sticky_position.deref();
+
+        let y = if let Some(top) = top {
+            let y = (viewport.min_y() + top).min(parent_area.max_y() - area.height());
+            (y - area.min_y()).max(0.0)
+        } else if let Some(bottom) = bottom {
+            let y = (viewport.max_y() - bottom - area.height()).max(parent_area.min_y());
+            (y - area.min_y()).min(0.0)
+        } else {
+            0.0
+        };
+
+        let x = if let Some(left) = left {
+            let x = (viewport.min_x() + left).min(parent_area.max_x() - area.width());
+            (x - area.min_x()).max(0.0)
+        } else if let Some(right) = right {
+            let x = (viewport.max_x() - right - area.width()).max(parent_area.min_x());
+            (x - area.min_x()).min(0.0)
+        } else {
+            0.0
+        };
+
+        Vector2D::new(x, y)
+    }
 }
 
 impl Scaled for Position {
     fn scale(&mut self, scale_factor: f32) {
-        if let Self::Absolute(absolute_postion) = self {
+        if let Self::Absolute(absolute_postion) | Self::Sticky(absolute_postion) = self {
             if let Some(top) = &mut absolute_postion.top {
                 *top *= scale_factor;
             }
@@ -16,6 +16,7 @@ pub enum Size {
     Percentage(Length),
     Pixels(Length),
     RootPercentage(Length),
+    Flex(Length),
     DynamicCalculations(Box<Vec<DynamicCalculation>>),
 }
 
@@ -46,6 +47,7 @@ impl Size {
             Size::Fill => "fill".to_string(),
             Size::FillMinimum => "fill-min".to_string(),
             Size::RootPercentage(p) => format!("{}% of root", p.get()),
+            Size::Flex(f) => format!("flex({})", f.get()),
         }
     }
 
@@ -61,10 +63,10 @@ impl Size {
             Size::Pixels(px) => Some(px.get() + parent_margin),
             Size::Percentage(per) => Some(parent_value / 100.0 * per.get()),
             Size::DynamicCalculations(calculations) => {
-                Some(run_calculations(calculations.deref(), parent_value))
+                run_calculations(calculations.deref(), parent_value, root_value)
             }
             Size::Fill => Some(available_parent_value),
-            Size::FillMinimum => {
+            Size::FillMinimum | Size::Flex(_) => {
                 if phase == Phase::Initial {
                     None
                 } else {
@@ -159,10 +161,23 @@ pub enum DynamicCalculation {
     Mul,
     Div,
     Add,
+    OpenParenthesis,
+    ClosedParenthesis,
+    FunctionSeparator,
+    Function(LexFunction),
     Percentage(f32),
+    RootPercentage(f32),
     Pixels(f32),
 }
 
+/// Functions that can be used inside a `calc()`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LexFunction {
+    Min,
+    Max,
+    Clamp,
+}
+
 impl Scaled for DynamicCalculation {
     fn scale(&mut self, scale_factor: f32) {
         if let DynamicCalculation::Pixels(s) = self {
@@ -171,6 +186,16 @@ impl Scaled for DynamicCalculation {
     }
 }
 
+impl std::fmt::Display for LexFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LexFunction::Min => f.write_str("min"),
+            LexFunction::Max => f.write_str("max"),
+            LexFunction::Clamp => f.write_str("clamp"),
+        }
+    }
+}
+
 impl std::fmt::Display for DynamicCalculation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -178,56 +203,154 @@ impl std::fmt::Display for DynamicCalculation {
             DynamicCalculation::Mul => f.write_str("*"),
             DynamicCalculation::Div => f.write_str("/"),
             DynamicCalculation::Add => f.write_str("+"),
+            DynamicCalculation::OpenParenthesis => f.write_str("("),
+            DynamicCalculation::ClosedParenthesis => f.write_str(")"),
+            DynamicCalculation::FunctionSeparator => f.write_str(","),
+            DynamicCalculation::Function(function) => function.fmt(f),
             DynamicCalculation::Percentage(p) => f.write_fmt(format_args!("{p}%")),
+            DynamicCalculation::RootPercentage(p) => f.write_fmt(format_args!("{p}v")),
             DynamicCalculation::Pixels(s) => f.write_fmt(format_args!("{s}")),
         }
     }
 }
 
-/// Calculate some chained operations with a given value.
-/// This value could be for example the width of a node's parent area.
-pub fn run_calculations(calcs: &[DynamicCalculation], value: f32) -> f32 {
-    let mut prev_number: Option<f32> = None;
-    let mut prev_op: Option<DynamicCalculation> = None;
-
-    let mut calc_with_op = |val: f32, prev_op: Option<DynamicCalculation>| {
-        if let Some(op) = prev_op {
-            match op {
-                DynamicCalculation::Sub => {
-                    prev_number = Some(prev_number.unwrap() - val);
+/// Recursive descent evaluator of a list of [DynamicCalculation].
+///
+/// Grammar, from lower to higher precedence:
+/// - expression: term (('+' | '-') term)*
+/// - term: factor (('*' | '/') factor)*
+/// - factor: ('+' | '-') factor | value | '(' expression ')' | function '(' expression (',' expression)* ')'
+struct DynamicCalculationEvaluator<'a> {
+    calcs: &'a [DynamicCalculation],
+    current: usize,
+    parent_value: f32,
+    root_value: f32,
+}
+
+impl<'a> DynamicCalculationEvaluator<'a> {
+    fn new(calcs: &'a [DynamicCalculation], parent_value: f32, root_value: f32) -> Self {
+        Self {
+            calcs,
+            current: 0,
+            parent_value,
+            root_value,
+        }
+    }
+
+    fn peek(&self) -> Option<&DynamicCalculation> {
+        self.calcs.get(self.current)
+    }
+
+    fn advance(&mut self) -> Option<&DynamicCalculation> {
+        let calc = self.calcs.get(self.current);
+        self.current += 1;
+        calc
+    }
+
+    /// Evaluate all the calculations, `None` is returned if they are not a valid expression
+    fn evaluate(mut self) -> Option<f32> {
+        let value = self.parse_expression()?;
+
+        // There must not be anything left after the expression
+        if self.current == self.calcs.len() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    fn parse_expression(&mut self) -> Option<f32> {
+        let mut value = self.parse_term()?;
+
+        loop {
+            match self.peek() {
+                Some(DynamicCalculation::Add) => {
+                    self.current += 1;
+                    value += self.parse_term()?;
                 }
-                DynamicCalculation::Add => {
-                    prev_number = Some(prev_number.unwrap() + val);
+                Some(DynamicCalculation::Sub) => {
+                    self.current += 1;
+                    value -= self.parse_term()?;
                 }
-                DynamicCalculation::Mul => {
-                    prev_number = Some(prev_number.unwrap() * val);
+                _ => return Some(value),
+            }
+        }
+    }
+
+    fn parse_term(&mut self) -> Option<f32> {
+        let mut value = self.parse_factor()?;
+
+        loop {
+            match self.peek() {
+                Some(DynamicCalculation::Mul) => {
+                    self.current += 1;
+                    value *= self.parse_factor()?;
                 }
-                DynamicCalculation::Div => {
-                    prev_number = Some(prev_number.unwrap() / val);
+                Some(DynamicCalculation::Div) => {
+                    self.current += 1;
+                    value /= self.parse_factor()?;
                 }
-                _ => {}
+                _ => return Some(value),
             }
-        } else {
-            prev_number = Some(val);
         }
-    };
+    }
 
-    for calc in calcs {
-        match calc {
-            DynamicCalculation::Percentage(per) => {
-                let val = (value / 100.0 * per).round();
+    fn parse_factor(&mut self) -> Option<f32> {
+        match *self.advance()? {
+            DynamicCalculation::Add => self.parse_factor(),
+            DynamicCalculation::Sub => self.parse_factor().map(|value| -value),
+            DynamicCalculation::Pixels(value) => Some(value),
+            DynamicCalculation::Percentage(per) => Some((self.parent_value / 100.0 * per).round()),
+            DynamicCalculation::RootPercentage(per) => {
+                Some((self.root_value / 100.0 * per).round())
+            }
+            DynamicCalculation::OpenParenthesis => {
+                let value = self.parse_expression()?;
+                self.expect_closed_parenthesis()?;
+                Some(value)
+            }
+            DynamicCalculation::Function(function) => {
+                let DynamicCalculation::OpenParenthesis = self.advance()? else {
+                    return None;
+                };
 
-                calc_with_op(val, prev_op);
+                let mut arguments = vec![self.parse_expression()?];
+                while let Some(DynamicCalculation::FunctionSeparator) = self.peek() {
+                    self.current += 1;
+                    arguments.push(self.parse_expression()?);
+                }
+                self.expect_closed_parenthesis()?;
 
-                prev_op = None;
-            }
-            DynamicCalculation::Pixels(val) => {
-                calc_with_op(*val, prev_op);
-                prev_op = None;
+                match function {
+                    LexFunction::Min => arguments.into_iter().reduce(f32::min),
+                    LexFunction::Max => arguments.into_iter().reduce(f32::max),
+                    LexFunction::Clamp => {
+                        let [minimum, value, maximum] = arguments[..] else {
+                            return None;
+                        };
+                        Some(value.max(minimum).min(maximum))
+                    }
+                }
             }
-            _ => prev_op = Some(*calc),
+            _ => None,
         }
     }
 
-    prev_number.unwrap()
+    fn expect_closed_parenthesis(&mut self) -> Option<()> {
+        match self.advance()? {
+            DynamicCalculation::ClosedParenthesis => Some(()),
+            _ => None,
+        }
+    }
+}
+
+/// Calculate an expression of [DynamicCalculation]s, percentages are relative to the given value,
+/// which could be for example the width of a node's parent area, and root percentages to the given root value.
+/// `None` is returned if the calculations are not a valid expression.
+pub fn run_calculations(
+    calcs: &[DynamicCalculation],
+    parent_value: f32,
+    root_value: f32,
+) -> Option<f32> {
+    DynamicCalculationEvaluator::new(calcs, parent_value, root_value).evaluate()
 }
@@ -0,0 +1,19 @@
+#[derive(PartialEq, Clone, Debug, Default)]
+pub enum WrapMode {
+    #[default]
+    NoWrap,
+    Wrap,
+}
+
+impl WrapMode {
+    pub fn is_wrap(&self) -> bool {
+        self == &Self::Wrap
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            WrapMode::NoWrap => "no-wrap".to_string(),
+            WrapMode::Wrap => "wrap".to_string(),
+        }
+    }
+}
@@ -333,3 +333,106 @@ pub fn nested_unsized_alignment() {
         Rect::new(Point2D::new(50.0, 50.0), Size2D::new(100.0, 100.0)),
     );
 }
+
+#[test]
+pub fn distributed_main_alignments() {
+    for (alignment, expected_x) in [
+        (Alignment::SpaceBetween, [0.0, 160.0, 320.0]),
+        (Alignment::SpaceAround, [20.0, 160.0, 300.0]),
+        (Alignment::SpaceEvenly, [30.0, 160.0, 290.0]),
+    ] {
+        let (mut layout, mut measurer) = test_utils();
+
+        let mut mocked_dom = TestingDOM::default();
+        mocked_dom.add(
+            0,
+            None,
+            vec![1, 2, 3],
+            Node::from_size_and_alignments_and_direction(
+                Size::Pixels(Length::new(420.0)),
+                Size::Pixels(Length::new(100.0)),
+                alignment,
+                Alignment::Start,
+                DirectionMode::Horizontal,
+            ),
+        );
+        for id in [1, 2, 3] {
+            mocked_dom.add(
+                id,
+                Some(0),
+                vec![],
+                Node::from_size_and_direction(
+                    Size::Pixels(Length::new(100.0)),
+                    Size::Pixels(Length::new(100.0)),
+                    DirectionMode::Vertical,
+                ),
+            );
+        }
+
+        layout.measure(
+            0,
+            Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+            &mut measurer,
+            &mut mocked_dom,
+        );
+
+        for (id, x) in [1, 2, 3].into_iter().zip(expected_x) {
+            assert_eq!(
+                layout.get(id).unwrap().area,
+                Rect::new(Point2D::new(x, 0.0), Size2D::new(100.0, 100.0)),
+            );
+        }
+    }
+}
+
+#[test]
+pub fn distributed_main_alignment_wrapped_lines() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            width: Size::Pixels(Length::new(300.0)),
+            height: Size::Pixels(Length::new(300.0)),
+            direction: DirectionMode::Horizontal,
+            main_alignment: Alignment::SpaceBetween,
+            wrap: WrapMode::Wrap,
+            ..Default::default()
+        },
+    );
+    for id in [1, 2, 3] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(120.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(120.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(180.0, 0.0), Size2D::new(120.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(120.0, 50.0)),
+    );
+}
@@ -0,0 +1,149 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn aspect_ratio_height_from_width() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(1000.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2],
+        Node::from_size_and_aspect_ratio(Size::Fill, Size::Inner, 16.0 / 9.0),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The height isn't affected by the inner Nodes
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 225.0)),
+    );
+}
+
+#[test]
+pub fn aspect_ratio_width_from_height() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(400.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Inner,
+            height: Size::Percentage(Length::new(50.0)),
+            margin: Gaps::new(10.0, 10.0, 10.0, 10.0),
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().visible_area(),
+        Rect::new(Point2D::new(10.0, 10.0), Size2D::new(360.0, 180.0)),
+    );
+}
+
+#[test]
+pub fn aspect_ratio_with_min_max() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(1000.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Fill,
+            height: Size::Inner,
+            maximum_height: Size::Pixels(Length::new(100.0)),
+            aspect_ratio: Some(1.0),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(100.0)),
+            height: Size::Inner,
+            minimum_height: Size::Pixels(Length::new(80.0)),
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(100.0, 80.0)),
+    );
+}
@@ -0,0 +1,252 @@
+use euclid::Length;
+use torin::{
+    content::Content,
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn flex_grow_horizontal() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction_and_content(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Horizontal,
+            Content::Flex,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Flex(Length::new(2.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Flex(Length::new(1.0)),
+            Size::Fill,
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(200.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(300.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn flex_grow_vertical_with_spacing() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node {
+            width: Size::Pixels(Length::new(100.0)),
+            height: Size::Pixels(Length::new(310.0)),
+            direction: DirectionMode::Vertical,
+            content: Content::Flex,
+            spacing: Length::new(10.0),
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Fill,
+            Size::Flex(Length::new(1.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Fill,
+            Size::Flex(Length::new(2.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 110.0), Size2D::new(100.0, 200.0)),
+    );
+}
+
+#[test]
+pub fn flex_shrink_respects_minimum_size() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction_and_content(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Horizontal,
+            Content::Flex,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            width: Size::Pixels(Length::new(200.0)),
+            height: Size::Pixels(Length::new(100.0)),
+            minimum_width: Size::Pixels(Length::new(180.0)),
+            flex_shrink: 1.0,
+            ..Default::default()
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_flex_shrink(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            1.0,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The first child stops at its minimum width so the second one shrinks the rest
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(180.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(180.0, 0.0), Size2D::new(70.0, 100.0)),
+    );
+    // Children without shrink keep their size
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(250.0, 0.0), Size2D::new(50.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn flex_without_flex_content() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Horizontal,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Flex(Length::new(2.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Flex sizes fill the remaining space when the parent has no flex content
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(300.0, 50.0)),
+    );
+}
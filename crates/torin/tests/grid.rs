@@ -0,0 +1,202 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn grid_tracks() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(500.0)),
+            Size::Pixels(Length::new(300.0)),
+            vec![
+                GridTrack::Pixels(Length::new(100.0)),
+                GridTrack::Weight(1.0),
+                GridTrack::Weight(2.0),
+            ],
+            vec![GridTrack::Percentage(Length::new(50.0)), GridTrack::Inner],
+        ),
+    );
+    for id in [1, 2, 3] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(Size::Fill, Size::Fill, DirectionMode::Vertical),
+        );
+    }
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(70.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(400.0 / 3.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(
+            Point2D::new(100.0 + 400.0 / 3.0, 0.0),
+            Size2D::new(800.0 / 3.0, 150.0)
+        ),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(50.0, 70.0)),
+    );
+    assert_eq!(
+        layout.get(0).unwrap().inner_sizes,
+        Size2D::new(500.0, 220.0),
+    );
+}
+
+#[test]
+pub fn grid_placement_and_span() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            vec![GridTrack::Weight(1.0); 3],
+            vec![GridTrack::Weight(1.0); 3],
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Fill,
+            Size::Fill,
+            GridPlacement::new(1, 2),
+            GridPlacement::new(0, 2),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Fill,
+            Size::Fill,
+            GridPlacement::default(),
+            GridPlacement::default(),
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Fill,
+            Size::Fill,
+            GridPlacement::default(),
+            GridPlacement::default(),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(200.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(100.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn grid_auto_sized_parent() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            direction: DirectionMode::Grid,
+            grid_columns: vec![GridTrack::Inner, GridTrack::Weight(1.0)],
+            main_alignment: Alignment::Center,
+            cross_alignment: Alignment::End,
+            ..Default::default()
+        },
+    );
+    for (id, width, height) in [(1, 50.0, 20.0), (2, 80.0, 40.0), (3, 30.0, 10.0)] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(width)),
+                Size::Pixels(Length::new(height)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(130.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 10.0), Size2D::new(50.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(50.0, 0.0), Size2D::new(80.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(20.0, 40.0), Size2D::new(30.0, 10.0)),
+    );
+}
@@ -114,3 +114,225 @@ pub fn position() {
         Rect::new(Point2D::new(100.0, 650.0), Size2D::new(200.0, 200.0)),
     );
 }
+
+fn sticky_dom(offset_y: f32) -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2],
+        Node::from_size_and_scroll(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(300.0)),
+            Length::new(0.0),
+            Length::new(offset_y),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![3, 5],
+        Node::from_size_and_direction(Size::Fill, Size::Inner, DirectionMode::Vertical),
+    );
+    let mut position = Position::new_sticky();
+    position.set_top(0.0);
+    mocked_dom.add(
+        3,
+        Some(2),
+        vec![4],
+        Node::from_size_and_position(Size::Fill, Size::Pixels(Length::new(50.0)), position),
+    );
+    mocked_dom.add(
+        4,
+        Some(3),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(10.0)),
+            Size::Pixels(Length::new(10.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        5,
+        Some(2),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Fill,
+            Size::Pixels(Length::new(400.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom
+}
+
+#[test]
+pub fn sticky_position_pinned_to_scroll_viewport() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = sticky_dom(-250.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 50.0)),
+    );
+    // Inner Nodes move with the sticky Node
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(10.0, 10.0)),
+    );
+    // Siblings are stacked as if the sticky Node wasn't pinned
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, -200.0), Size2D::new(400.0, 400.0)),
+    );
+}
+
+#[test]
+pub fn sticky_position_leaves_with_its_parent() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = sticky_dom(-420.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The parent ends at 30, so the sticky Node can't go any further than -20
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, -20.0), Size2D::new(400.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn sticky_position_not_scrolled() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = sticky_dom(0.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 50.0)),
+    );
+
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(400.0, 400.0)),
+    );
+}
+
+#[test]
+pub fn sticky_position_partial_measure() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = sticky_dom(-250.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Measuring again only a part of the scroll container keeps the sticky Node pinned
+    mocked_dom.set_node(
+        5,
+        Node::from_size_and_direction(
+            Size::Fill,
+            Size::Pixels(Length::new(300.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    layout.invalidate(5);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, -200.0), Size2D::new(400.0, 300.0)),
+    );
+}
+
+#[test]
+pub fn sticky_position_bottom() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_scroll(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(300.0)),
+            Length::new(0.0),
+            Length::new(0.0),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Fill,
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    let mut position = Position::new_sticky();
+    position.set_bottom(0.0);
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_position(Size::Fill, Size::Pixels(Length::new(50.0)), position),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 250.0), Size2D::new(400.0, 50.0)),
+    );
+}
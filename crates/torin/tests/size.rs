@@ -718,3 +718,127 @@ pub fn content_fit_fill_min() {
         Rect::new(Point2D::new(0.0, 600.0), Size2D::new(100.0, 300.0)),
     );
 }
+
+#[test]
+pub fn dynamic_calculations_precedence() {
+    use DynamicCalculation::*;
+
+    // 100 + 50% * 2 of 200
+    let calcs = [Pixels(100.0), Add, Percentage(50.0), Mul, Pixels(2.0)];
+    assert_eq!(run_calculations(&calcs, 200.0, 1000.0), Some(300.0));
+
+    // (100 + 50%) * 2 of 200
+    let calcs = [
+        OpenParenthesis,
+        Pixels(100.0),
+        Add,
+        Percentage(50.0),
+        ClosedParenthesis,
+        Mul,
+        Pixels(2.0),
+    ];
+    assert_eq!(run_calculations(&calcs, 200.0, 1000.0), Some(400.0));
+
+    // -10 - -10v of 1000 root
+    let calcs = [Sub, Pixels(10.0), Sub, Sub, RootPercentage(10.0)];
+    assert_eq!(run_calculations(&calcs, 200.0, 1000.0), Some(90.0));
+}
+
+#[test]
+pub fn dynamic_calculations_functions() {
+    use DynamicCalculation::*;
+
+    let calcs = [
+        Function(LexFunction::Min),
+        OpenParenthesis,
+        Percentage(50.0),
+        FunctionSeparator,
+        Pixels(80.0),
+        FunctionSeparator,
+        Pixels(150.0),
+        ClosedParenthesis,
+    ];
+    assert_eq!(run_calculations(&calcs, 200.0, 1000.0), Some(80.0));
+
+    let calcs = [
+        Function(LexFunction::Max),
+        OpenParenthesis,
+        Percentage(50.0),
+        FunctionSeparator,
+        Pixels(80.0),
+        ClosedParenthesis,
+    ];
+    assert_eq!(run_calculations(&calcs, 200.0, 1000.0), Some(100.0));
+
+    let calcs = [
+        Function(LexFunction::Clamp),
+        OpenParenthesis,
+        Pixels(50.0),
+        FunctionSeparator,
+        Percentage(100.0),
+        Sub,
+        Pixels(10.0),
+        FunctionSeparator,
+        Pixels(150.0),
+        ClosedParenthesis,
+    ];
+    assert_eq!(run_calculations(&calcs, 200.0, 1000.0), Some(150.0));
+
+    // Clamp requires exactly three arguments
+    let calcs = [
+        Function(LexFunction::Clamp),
+        OpenParenthesis,
+        Pixels(50.0),
+        ClosedParenthesis,
+    ];
+    assert_eq!(run_calculations(&calcs, 200.0, 1000.0), None);
+}
+
+#[test]
+pub fn dynamic_calculations_size() {
+    use DynamicCalculation::*;
+
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(400.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::DynamicCalculations(Box::new(vec![
+                OpenParenthesis,
+                Percentage(100.0),
+                Sub,
+                Pixels(100.0),
+                ClosedParenthesis,
+                Div,
+                Pixels(2.0),
+            ])),
+            Size::DynamicCalculations(Box::new(vec![RootPercentage(10.0), Add, Pixels(5.0)])),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(150.0, 105.0)),
+    );
+}
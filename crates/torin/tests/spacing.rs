@@ -0,0 +1,214 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn spacing_vertical_auto_sized() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(500.0)),
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3, 4],
+        Node::from_size_and_direction_and_spacing(
+            Size::Inner,
+            Size::Inner,
+            DirectionMode::Vertical,
+            Length::new(10.0),
+        ),
+    );
+    for id in [2, 3, 4] {
+        mocked_dom.add(
+            id,
+            Some(1),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 170.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().inner_sizes,
+        Size2D::new(100.0, 170.0),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 60.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 120.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn spacing_horizontal_with_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node {
+            width: Size::Pixels(Length::new(300.0)),
+            height: Size::Pixels(Length::new(100.0)),
+            direction: DirectionMode::Horizontal,
+            main_alignment: Alignment::End,
+            spacing: Length::new(20.0),
+            ..Default::default()
+        },
+    );
+    for id in [1, 2] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(80.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(200.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn spacing_wrapped_lines() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            width: Size::Pixels(Length::new(215.0)),
+            height: Size::Inner,
+            direction: DirectionMode::Horizontal,
+            wrap: WrapMode::Wrap,
+            spacing: Length::new(10.0),
+            ..Default::default()
+        },
+    );
+    for id in [1, 2, 3] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().inner_sizes,
+        Size2D::new(210.0, 110.0),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 60.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn spacing_grid() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            width: Size::Pixels(Length::new(210.0)),
+            height: Size::Pixels(Length::new(210.0)),
+            direction: DirectionMode::Grid,
+            grid_columns: vec![GridTrack::Weight(1.0); 2],
+            grid_rows: vec![GridTrack::Weight(1.0); 2],
+            spacing: Length::new(10.0),
+            ..Default::default()
+        },
+    );
+    for id in [1, 2, 3] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(Size::Fill, Size::Fill, DirectionMode::Vertical),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 110.0), Size2D::new(100.0, 100.0)),
+    );
+}
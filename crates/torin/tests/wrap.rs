@@ -0,0 +1,236 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn wrap_horizontal() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction_and_wrap(
+            Size::Pixels(Length::new(250.0)),
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Horizontal,
+            WrapMode::Wrap,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(80.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(100.0, 80.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 80.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn wrap_vertical() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction_and_wrap(
+            Size::Pixels(Length::new(500.0)),
+            Size::Pixels(Length::new(150.0)),
+            DirectionMode::Vertical,
+            WrapMode::Wrap,
+        ),
+    );
+    for (id, width) in [(1, 60.0), (2, 40.0), (3, 50.0)] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(width)),
+                Size::Pixels(Length::new(70.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(60.0, 70.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 70.0), Size2D::new(40.0, 70.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(60.0, 0.0), Size2D::new(50.0, 70.0)),
+    );
+}
+
+#[test]
+pub fn wrap_auto_sized_parent() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(250.0)),
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3, 4],
+        Node::from_size_and_direction_and_wrap(
+            Size::Inner,
+            Size::Inner,
+            DirectionMode::Horizontal,
+            WrapMode::Wrap,
+        ),
+    );
+    for id in [2, 3, 4] {
+        mocked_dom.add(
+            id,
+            Some(1),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().inner_sizes,
+        Size2D::new(200.0, 100.0),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn wrap_with_alignments() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            width: Size::Pixels(Length::new(250.0)),
+            height: Size::Pixels(Length::new(500.0)),
+            direction: DirectionMode::Horizontal,
+            main_alignment: Alignment::Center,
+            cross_alignment: Alignment::End,
+            wrap: WrapMode::Wrap,
+            ..Default::default()
+        },
+    );
+    for (id, height) in [(1, 50.0), (2, 80.0), (3, 50.0)] {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(height)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(25.0, 30.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(125.0, 0.0), Size2D::new(100.0, 80.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(75.0, 80.0), Size2D::new(100.0, 50.0)),
+    );
+}
@@ -0,0 +1,49 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::{
+    headless::HeadlessConfig,
+    prelude::*,
+};
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            height: "fill",
+            width: "fill",
+            main_align: "center",
+            cross_align: "center",
+            background: "rgb(0, 119, 182)",
+            color: "white",
+            label {
+                font_size: "50",
+                font_weight: "bold",
+                "Hello, World!"
+            }
+        }
+    )
+}
+
+/// Usage: `cargo run --example headless_screenshot -- [path] [width] [height] [scale factor]`
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().unwrap_or("./screenshot.png".to_string());
+    let mut number = |default: f64| {
+        args.next()
+            .and_then(|arg| arg.parse().ok())
+            .unwrap_or(default)
+    };
+    let width = number(600.0);
+    let height = number(400.0);
+    let scale_factor = number(1.0);
+
+    let config = HeadlessConfig::new()
+        .with_size((width as f32, height as f32))
+        .with_scale_factor(scale_factor);
+
+    freya::headless::save(app, config, &path).expect("Failed to save the screenshot.");
+
+    println!("Saved {path}");
+}